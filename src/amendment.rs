@@ -0,0 +1,199 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::constants::BASE_URL;
+use crate::error::Error;
+use crate::{Chamber, Citation, CongObjectType, Congress, Result};
+
+/// Represents an amendment to a measure, e.g. `118hamdt1` for House Amendment 1 in the 118th
+/// Congress.
+///
+/// Amendments are numbered per chamber and Congress. The measure the amendment was offered to
+/// is not part of the citation, but can be recorded with `Amendment::with_parent`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amendment {
+    congress: Congress,
+    chamber: Chamber,
+    number: usize,
+    parent: Option<Citation>,
+}
+
+impl Amendment {
+    /// Parse an amendment citation.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Amendment, Chamber};
+    ///
+    /// let amendment = Amendment::parse("118samdt12").unwrap();
+    /// assert_eq!(&Chamber::Senate, amendment.chamber());
+    /// assert_eq!(12, amendment.number());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in the same errors as [`Citation::parse`], which rejects a version suffix on an
+    /// amendment, or in `Error::NotAnAmendment` if the citation is valid but cites something
+    /// other than an amendment.
+    pub fn parse(input: &str) -> Result<Self> {
+        let citation = Citation::parse(input)?;
+        match citation.object_type() {
+            CongObjectType::HouseAmendment | CongObjectType::SenateAmendment => Ok(Amendment {
+                congress: *citation.congress(),
+                chamber: *citation.chamber(),
+                number: citation.number(),
+                parent: None,
+            }),
+            _ => Err(Error::NotAnAmendment),
+        }
+    }
+
+    /// Record the measure the amendment was offered to.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Amendment, Citation};
+    ///
+    /// let bill = Citation::parse("118hr815").unwrap();
+    /// let amendment = Amendment::parse("118samdt1").unwrap().with_parent(bill.clone()).unwrap();
+    /// assert_eq!(Some(&bill), amendment.parent());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in `Error::AmendmentCongressMismatch` if the measure is from a different
+    /// Congress than the amendment.
+    pub fn with_parent(self, parent: Citation) -> Result<Self> {
+        if parent.congress() != &self.congress {
+            return Err(Error::AmendmentCongressMismatch);
+        }
+        Ok(Amendment {
+            parent: Some(parent),
+            ..self
+        })
+    }
+
+    /// Get the amendment's Congress.
+    pub fn congress(&self) -> &Congress {
+        &self.congress
+    }
+
+    /// Get the chamber in which the amendment was offered.
+    pub fn chamber(&self) -> &Chamber {
+        &self.chamber
+    }
+
+    /// Get the amendment number.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Get the measure the amendment was offered to, if one was recorded.
+    pub fn parent(&self) -> Option<&Citation> {
+        self.parent.as_ref()
+    }
+
+    /// Converts an `Amendment` to a URL on Congress.gov.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Amendment;
+    ///
+    /// let url = "118hamdt1".parse::<Amendment>().unwrap().to_url();
+    /// assert_eq!(
+    ///     "https://www.congress.gov/amendment/118th-congress/house-amendment/1",
+    ///     url
+    /// );
+    /// ```
+    pub fn to_url(&self) -> String {
+        format!(
+            "{BASE_URL}/amendment/{}-congress/{}-amendment/{}",
+            self.congress.as_ordinal(),
+            self.chamber,
+            self.number
+        )
+    }
+}
+
+impl Display for Amendment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{}amdt{}",
+            self.congress,
+            self.chamber.code(),
+            self.number
+        )
+    }
+}
+
+impl FromStr for Amendment {
+    type Err = Error;
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_amendment() {
+        let expected = Amendment {
+            congress: Congress(118),
+            chamber: Chamber::House,
+            number: 1,
+            parent: None,
+        };
+        assert_eq!(expected, "118hamdt1".parse().unwrap());
+        assert_eq!(expected, "118-HAMDT-1".parse().unwrap());
+        assert_eq!("118hamdt1", expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_amendment_errors() {
+        assert_eq!(Err(Error::NotAnAmendment), Amendment::parse("118hr815"));
+        assert_eq!(Err(Error::NotAnAmendment), Amendment::parse("118hr815ih"));
+        assert_eq!(
+            Error::InvalidBillVersion,
+            *Amendment::parse("118samdt1ih").unwrap_err().root_cause()
+        );
+        assert_eq!(
+            Error::InvalidBillVersion,
+            *Amendment::parse("118-HAMDT-1-ENR")
+                .unwrap_err()
+                .root_cause()
+        );
+        assert_eq!(
+            Error::InvalidBillNumber,
+            *Amendment::parse("118samdt").unwrap_err().root_cause()
+        );
+    }
+
+    #[test]
+    fn test_amendment_with_parent() {
+        let amendment = Amendment::parse("118samdt3").unwrap();
+        assert_eq!(
+            Err(Error::AmendmentCongressMismatch),
+            amendment
+                .clone()
+                .with_parent(Citation::parse("117s5").unwrap())
+        );
+        let parent = Citation::parse("118s5").unwrap();
+        let amendment = amendment.with_parent(parent.clone()).unwrap();
+        assert_eq!(Some(&parent), amendment.parent());
+    }
+
+    #[test]
+    fn test_amendment_to_url() {
+        let amendment = "117samdt2137".parse::<Amendment>().unwrap();
+        let expected = "https://www.congress.gov/amendment/117th-congress/senate-amendment/2137";
+        assert_eq!(expected, amendment.to_url());
+    }
+}
@@ -0,0 +1,104 @@
+use core::fmt::Display;
+
+/// A chamber of Congress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Chamber {
+    /// The House of Representatives.
+    House,
+    /// The Senate.
+    Senate,
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::House => "house",
+                Self::Senate => "senate",
+            }
+        )
+    }
+}
+
+impl Chamber {
+    /// Iterate over both chambers, the House and then the Senate.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Chamber;
+    ///
+    /// let chambers: Vec<_> = Chamber::iter().collect();
+    /// assert_eq!(vec![Chamber::House, Chamber::Senate], chambers);
+    /// ```
+    pub fn iter() -> core::array::IntoIter<Chamber, 2> {
+        [Self::House, Self::Senate].into_iter()
+    }
+
+    pub(crate) fn parse(input: u8) -> Self {
+        if input.eq_ignore_ascii_case(&b'h') {
+            Self::House
+        } else {
+            Self::Senate
+        }
+    }
+
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::House => "h",
+            Self::Senate => "s",
+        }
+    }
+
+    /// The capitalized name used in Congress.gov searches.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::House => "House",
+            Self::Senate => "Senate",
+        }
+    }
+
+    /// Get the uppercase single-letter abbreviation used in formal documents.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Chamber;
+    ///
+    /// assert_eq!("H", Chamber::House.abbreviation());
+    /// assert_eq!("S", Chamber::Senate.abbreviation());
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::House => "H",
+            Self::Senate => "S",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chamber_abbreviation_round_trip() {
+        for chamber in [Chamber::House, Chamber::Senate] {
+            assert_eq!(
+                chamber,
+                Chamber::parse(chamber.abbreviation().as_bytes()[0])
+            );
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        let chambers: Vec<_> = Chamber::iter().collect();
+        assert!(chambers.is_sorted());
+        for chamber in chambers {
+            assert_eq!(chamber, Chamber::parse(chamber.code().as_bytes()[0]));
+        }
+    }
+}
@@ -0,0 +1,848 @@
+use core::fmt::Display;
+
+use crate::error::Error;
+use crate::{Chamber, Result};
+
+/// The kind of congressional document a citation refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CongObjectType {
+    /// A Senate bill, e.g. `118s5`.
+    SenateBill,
+    /// A House bill, e.g. `118hr815`.
+    HouseBill,
+    /// A Senate private bill, for the relief of specific individuals, e.g. `118sprivb1`.
+    ///
+    /// Private bills share the numbering of public bills, and Congress.gov does not distinguish
+    /// them in its URLs, so a private bill links to the same `/bill/` page as a public bill with
+    /// its number.
+    SenatePrivateBill,
+    /// A House private bill, e.g. `118hprivb1`. See [`CongObjectType::SenatePrivateBill`].
+    HousePrivateBill,
+    /// A Senate simple resolution, e.g. `118sres1`.
+    SenateResolution,
+    /// A House simple resolution, e.g. `118hres1`.
+    HouseResolution,
+    /// A Senate concurrent resolution, e.g. `118sconres1`.
+    SenateConcurrentResolution,
+    /// A House concurrent resolution, e.g. `118hconres1`.
+    HouseConcurrentResolution,
+    /// A Senate joint resolution, e.g. `118sjres1`.
+    SenateJointResolution,
+    /// A House joint resolution, e.g. `118hjres1`.
+    HouseJointResolution,
+    /// A House committee report, e.g. `118hrpt529`.
+    HouseReport,
+    /// A Senate committee report, e.g. `118srpt17`.
+    SenateReport,
+    /// A House conference report, reconciling the House and Senate versions of a measure, e.g.
+    /// `118hconf5`.
+    ///
+    /// Conference reports share the numbering of committee reports, and Congress.gov does not
+    /// distinguish them in its URLs, so a conference report links to the same `/house-report/`
+    /// page as a committee report with its number.
+    HouseConferenceReport,
+    /// A Senate conference report, e.g. `118sconf1`. See
+    /// [`CongObjectType::HouseConferenceReport`].
+    SenateConferenceReport,
+    /// A Senate executive report on a treaty or nomination, e.g. `118erpt1`.
+    ///
+    /// Executive reports are cited without a chamber letter.
+    SenateExecutiveReport,
+    /// A House amendment, e.g. `118hamdt1`.
+    HouseAmendment,
+    /// A Senate amendment, e.g. `118samdt1`.
+    SenateAmendment,
+    /// A House document, e.g. `118hdoc1`.
+    HouseDocument,
+    /// A Senate document, e.g. `118sdoc1`.
+    SenateDocument,
+    /// A House committee print, a working document issued by a committee, e.g. `118hprt1`.
+    HousePrint,
+    /// A Senate committee print, e.g. `118sprt1`.
+    SenatePrint,
+}
+
+impl CongObjectType {
+    /// Every object type, in declaration order.
+    pub(crate) const ALL: [Self; 21] = [
+        Self::SenateBill,
+        Self::HouseBill,
+        Self::SenatePrivateBill,
+        Self::HousePrivateBill,
+        Self::SenateResolution,
+        Self::HouseResolution,
+        Self::SenateConcurrentResolution,
+        Self::HouseConcurrentResolution,
+        Self::SenateJointResolution,
+        Self::HouseJointResolution,
+        Self::HouseReport,
+        Self::SenateReport,
+        Self::HouseConferenceReport,
+        Self::SenateConferenceReport,
+        Self::SenateExecutiveReport,
+        Self::HouseAmendment,
+        Self::SenateAmendment,
+        Self::HouseDocument,
+        Self::SenateDocument,
+        Self::HousePrint,
+        Self::SenatePrint,
+    ];
+
+    /// The tag identifying the object type in `Citation::to_bytes`.
+    ///
+    /// Tags are fixed once assigned, so that encoded citations keep decoding as variants are
+    /// added: a new object type takes the next unused tag, and no tag is ever reused.
+    pub(crate) fn binary_tag(&self) -> u8 {
+        match self {
+            Self::SenateBill => 0,
+            Self::HouseBill => 1,
+            Self::SenatePrivateBill => 2,
+            Self::HousePrivateBill => 3,
+            Self::SenateResolution => 4,
+            Self::HouseResolution => 5,
+            Self::SenateConcurrentResolution => 6,
+            Self::HouseConcurrentResolution => 7,
+            Self::SenateJointResolution => 8,
+            Self::HouseJointResolution => 9,
+            Self::HouseReport => 10,
+            Self::SenateReport => 11,
+            Self::HouseConferenceReport => 12,
+            Self::SenateConferenceReport => 13,
+            Self::SenateExecutiveReport => 14,
+            Self::HouseAmendment => 15,
+            Self::SenateAmendment => 16,
+            Self::HouseDocument => 17,
+            Self::SenateDocument => 18,
+            Self::HousePrint => 19,
+            Self::SenatePrint => 20,
+        }
+    }
+
+    /// The object type with the given `binary_tag`, if any.
+    pub(crate) fn from_binary_tag(tag: u8) -> Option<Self> {
+        Self::iter().find(|object_type| object_type.binary_tag() == tag)
+    }
+
+    /// Iterate over every object type, in declaration order.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// let bills: Vec<_> = CongObjectType::iter().filter(CongObjectType::is_bill).collect();
+    /// assert_eq!(4, bills.len());
+    /// assert_eq!(Some(CongObjectType::SenateBill), CongObjectType::iter().next());
+    /// ```
+    pub fn iter() -> impl Iterator<Item = CongObjectType> {
+        Self::ALL.into_iter()
+    }
+
+    pub(crate) fn parse(input: &[u8], chamber: &Chamber) -> Result<Self> {
+        match input.to_ascii_lowercase().as_slice() {
+            b"" | b"r" if *chamber == Chamber::House => Ok(Self::HouseBill),
+            b"" if *chamber == Chamber::Senate => Ok(Self::SenateBill),
+            b"privb" if *chamber == Chamber::House => Ok(Self::HousePrivateBill),
+            b"privb" if *chamber == Chamber::Senate => Ok(Self::SenatePrivateBill),
+            b"res" if *chamber == Chamber::House => Ok(Self::HouseResolution),
+            b"res" if *chamber == Chamber::Senate => Ok(Self::SenateResolution),
+            b"conres" if *chamber == Chamber::House => Ok(Self::HouseConcurrentResolution),
+            b"conres" if *chamber == Chamber::Senate => Ok(Self::SenateConcurrentResolution),
+            b"jres" if *chamber == Chamber::House => Ok(Self::HouseJointResolution),
+            b"jres" if *chamber == Chamber::Senate => Ok(Self::SenateJointResolution),
+            b"rpt" if *chamber == Chamber::House => Ok(Self::HouseReport),
+            b"rpt" if *chamber == Chamber::Senate => Ok(Self::SenateReport),
+            b"conf" if *chamber == Chamber::House => Ok(Self::HouseConferenceReport),
+            b"conf" if *chamber == Chamber::Senate => Ok(Self::SenateConferenceReport),
+            b"erpt" if *chamber == Chamber::Senate => Ok(Self::SenateExecutiveReport),
+            b"amdt" if *chamber == Chamber::House => Ok(Self::HouseAmendment),
+            b"amdt" if *chamber == Chamber::Senate => Ok(Self::SenateAmendment),
+            b"doc" if *chamber == Chamber::House => Ok(Self::HouseDocument),
+            b"doc" if *chamber == Chamber::Senate => Ok(Self::SenateDocument),
+            b"prt" if *chamber == Chamber::House => Ok(Self::HousePrint),
+            b"prt" if *chamber == Chamber::Senate => Ok(Self::SenatePrint),
+            _ => Err(Error::UnknownCongObjectType),
+        }
+    }
+
+    /// Parse an object type abbreviation, such as `"res"` or `"jres"`, for the given chamber.
+    ///
+    /// The abbreviation excludes the chamber letter, as returned by
+    /// [`CongObjectType::abbreviation`]. For the variant that includes it, see the
+    /// `TryFrom<&str>` implementation.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Chamber, CongObjectType};
+    ///
+    /// assert_eq!(
+    ///     CongObjectType::SenateConcurrentResolution,
+    ///     CongObjectType::from_abbreviation("conres", &Chamber::Senate).unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the abbreviation is not a recognized object type for the
+    /// chamber.
+    pub fn from_abbreviation(abbreviation: &str, chamber: &Chamber) -> Result<Self> {
+        Self::parse(abbreviation.as_bytes(), chamber)
+    }
+
+    pub(crate) fn from_url_segment(segment: &str) -> Result<Self> {
+        let (chamber, object_type) = segment
+            .split_once('-')
+            .ok_or(Error::UnknownCongObjectType)?;
+        let chamber = match chamber {
+            "house" => Chamber::House,
+            "senate" => Chamber::Senate,
+            _ => return Err(Error::UnknownCongObjectType),
+        };
+        let abbreviation: &[u8] = match object_type {
+            "bill" => b"",
+            "resolution" => b"res",
+            "concurrent-resolution" => b"conres",
+            "joint-resolution" => b"jres",
+            "report" => b"rpt",
+            "amendment" => b"amdt",
+            "document" => b"doc",
+            "print" => b"prt",
+            _ => return Err(Error::UnknownCongObjectType),
+        };
+        Self::parse(abbreviation, &chamber)
+    }
+
+    pub(crate) fn chamber(&self) -> Chamber {
+        match self {
+            Self::HouseBill
+            | Self::HousePrivateBill
+            | Self::HouseResolution
+            | Self::HouseConcurrentResolution
+            | Self::HouseJointResolution
+            | Self::HouseReport
+            | Self::HouseConferenceReport
+            | Self::HouseAmendment
+            | Self::HouseDocument
+            | Self::HousePrint => Chamber::House,
+            Self::SenateBill
+            | Self::SenatePrivateBill
+            | Self::SenateResolution
+            | Self::SenateConcurrentResolution
+            | Self::SenateJointResolution
+            | Self::SenateReport
+            | Self::SenateConferenceReport
+            | Self::SenateExecutiveReport
+            | Self::SenateAmendment
+            | Self::SenateDocument
+            | Self::SenatePrint => Chamber::Senate,
+        }
+    }
+
+    /// Get the collection segment of Congress.gov URLs for documents of this type, the first
+    /// path segment after the domain.
+    ///
+    /// Bills and resolutions are in `"bill"`, committee and conference reports in
+    /// `"congressional-report"`, Senate executive reports in `"executive-report"`, amendments in
+    /// `"amendment"`, and documents and committee prints in `"congressional-document"`.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert_eq!("bill", CongObjectType::HouseJointResolution.collection());
+    /// assert_eq!("congressional-report", CongObjectType::SenateReport.collection());
+    /// assert_eq!("congressional-document", CongObjectType::HousePrint.collection());
+    /// ```
+    pub fn collection(&self) -> &'static str {
+        match self {
+            _ if self.is_measure() => "bill",
+            Self::HouseReport
+            | Self::SenateReport
+            | Self::HouseConferenceReport
+            | Self::SenateConferenceReport => "congressional-report",
+            Self::SenateExecutiveReport => "executive-report",
+            Self::HouseAmendment | Self::SenateAmendment => "amendment",
+            _ => "congressional-document",
+        }
+    }
+
+    /// The type code used by GovInfo and the Congress.gov API, which file private bills alongside
+    /// public bills and conference reports alongside committee reports.
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Self::HouseBill | Self::HousePrivateBill => "hr",
+            Self::SenateBill | Self::SenatePrivateBill => "s",
+            Self::HouseResolution => "hres",
+            Self::SenateResolution => "sres",
+            Self::HouseConcurrentResolution => "hconres",
+            Self::SenateConcurrentResolution => "sconres",
+            Self::HouseJointResolution => "hjres",
+            Self::SenateJointResolution => "sjres",
+            Self::HouseReport | Self::HouseConferenceReport => "hrpt",
+            Self::SenateReport | Self::SenateConferenceReport => "srpt",
+            Self::SenateExecutiveReport => "erpt",
+            Self::HouseAmendment => "hamdt",
+            Self::SenateAmendment => "samdt",
+            Self::HouseDocument => "hdoc",
+            Self::SenateDocument => "sdoc",
+            Self::HousePrint => "hprt",
+            Self::SenatePrint => "sprt",
+        }
+    }
+
+    /// Whether citations and Congress.gov URLs for the object type name its chamber.
+    pub(crate) fn is_cited_by_chamber(&self) -> bool {
+        *self != Self::SenateExecutiveReport
+    }
+
+    pub(crate) fn api_collection(&self) -> Option<&'static str> {
+        match self {
+            Self::HouseReport
+            | Self::SenateReport
+            | Self::HouseConferenceReport
+            | Self::SenateConferenceReport => Some("committee-report"),
+            Self::HouseAmendment | Self::SenateAmendment => Some("amendment"),
+            Self::HouseDocument
+            | Self::SenateDocument
+            | Self::SenateExecutiveReport
+            | Self::HousePrint
+            | Self::SenatePrint => None,
+            _ => Some("bill"),
+        }
+    }
+
+    /// Get the GovInfo collection, or document class, that publishes documents of this type.
+    ///
+    /// Bills and resolutions are published in `BILLS`, committee reports in `CRPT`, and
+    /// documents in `CDOC`. GovInfo does not publish amendments separately, so they have none, and
+    /// it files committee prints by jacket number rather than print number, so they have none
+    /// either.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert_eq!(Some("BILLS"), CongObjectType::HouseBill.govinfo_docclass());
+    /// assert_eq!(Some("CRPT"), CongObjectType::SenateReport.govinfo_docclass());
+    /// assert_eq!(None, CongObjectType::HouseAmendment.govinfo_docclass());
+    /// ```
+    pub fn govinfo_docclass(&self) -> Option<&'static str> {
+        match self {
+            _ if self.is_report() => Some("CRPT"),
+            Self::HouseDocument | Self::SenateDocument => Some("CDOC"),
+            Self::HouseAmendment | Self::SenateAmendment | Self::HousePrint | Self::SenatePrint => {
+                None
+            }
+            _ => Some("BILLS"),
+        }
+    }
+
+    /// Get the abbreviation the object type uses in citations, after the chamber letter.
+    ///
+    /// This is the inverse of parsing the object type: the House bill in `"118hr815"` is
+    /// abbreviated `"r"`, and Senate bills, cited as `"118s5"`, have an empty abbreviation.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert_eq!("r", CongObjectType::HouseBill.abbreviation());
+    /// assert_eq!("res", CongObjectType::SenateResolution.abbreviation());
+    /// assert_eq!("rpt", CongObjectType::HouseReport.abbreviation());
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::HouseBill => "r",
+            Self::SenateBill => "",
+            Self::HousePrivateBill | Self::SenatePrivateBill => "privb",
+            Self::HouseResolution | Self::SenateResolution => "res",
+            Self::HouseConcurrentResolution | Self::SenateConcurrentResolution => "conres",
+            Self::HouseJointResolution | Self::SenateJointResolution => "jres",
+            Self::HouseReport | Self::SenateReport => "rpt",
+            Self::HouseConferenceReport | Self::SenateConferenceReport => "conf",
+            Self::SenateExecutiveReport => "erpt",
+            Self::HouseAmendment | Self::SenateAmendment => "amdt",
+            Self::HouseDocument | Self::SenateDocument => "doc",
+            Self::HousePrint | Self::SenatePrint => "prt",
+        }
+    }
+
+    /// Get the designation the Government Publishing Office uses for bills and resolutions of
+    /// this type, e.g. "H.J.Res." for a House joint resolution.
+    ///
+    /// Returns `None` for private bills and for documents other than bills and resolutions.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert_eq!(Some("H.R."), CongObjectType::HouseBill.gpo_designation());
+    /// assert_eq!(
+    ///     Some("S.Con.Res."),
+    ///     CongObjectType::SenateConcurrentResolution.gpo_designation()
+    /// );
+    /// assert_eq!(None, CongObjectType::HouseReport.gpo_designation());
+    /// ```
+    pub fn gpo_designation(&self) -> Option<&'static str> {
+        match self {
+            Self::HouseBill => Some("H.R."),
+            Self::SenateBill => Some("S."),
+            Self::HouseResolution => Some("H.Res."),
+            Self::SenateResolution => Some("S.Res."),
+            Self::HouseConcurrentResolution => Some("H.Con.Res."),
+            Self::SenateConcurrentResolution => Some("S.Con.Res."),
+            Self::HouseJointResolution => Some("H.J.Res."),
+            Self::SenateJointResolution => Some("S.J.Res."),
+            _ => None,
+        }
+    }
+
+    /// Get the human-readable name of the object type, e.g. "House Concurrent Resolution".
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert_eq!("House Bill", CongObjectType::HouseBill.full_name());
+    /// assert_eq!(
+    ///     "Senate Joint Resolution",
+    ///     CongObjectType::SenateJointResolution.full_name()
+    /// );
+    /// ```
+    pub fn full_name(&self) -> &'static str {
+        match self {
+            Self::SenateBill => "Senate Bill",
+            Self::HouseBill => "House Bill",
+            Self::SenatePrivateBill => "Senate Private Bill",
+            Self::HousePrivateBill => "House Private Bill",
+            Self::SenateResolution => "Senate Resolution",
+            Self::HouseResolution => "House Resolution",
+            Self::SenateConcurrentResolution => "Senate Concurrent Resolution",
+            Self::HouseConcurrentResolution => "House Concurrent Resolution",
+            Self::SenateJointResolution => "Senate Joint Resolution",
+            Self::HouseJointResolution => "House Joint Resolution",
+            Self::HouseReport => "House Report",
+            Self::SenateReport => "Senate Report",
+            Self::HouseConferenceReport => "House Conference Report",
+            Self::SenateConferenceReport => "Senate Conference Report",
+            Self::SenateExecutiveReport => "Senate Executive Report",
+            Self::HouseAmendment => "House Amendment",
+            Self::SenateAmendment => "Senate Amendment",
+            Self::HouseDocument => "House Document",
+            Self::SenateDocument => "Senate Document",
+            Self::HousePrint => "House Committee Print",
+            Self::SenatePrint => "Senate Committee Print",
+        }
+    }
+
+    /// Check whether the object type is a House or Senate bill, public or private.
+    pub fn is_bill(&self) -> bool {
+        matches!(
+            self,
+            Self::HouseBill | Self::SenateBill | Self::HousePrivateBill | Self::SenatePrivateBill
+        )
+    }
+
+    /// Check whether the object type is a simple, concurrent, or joint resolution of either
+    /// chamber.
+    pub fn is_resolution(&self) -> bool {
+        matches!(
+            self,
+            Self::HouseResolution
+                | Self::SenateResolution
+                | Self::HouseConcurrentResolution
+                | Self::SenateConcurrentResolution
+                | Self::HouseJointResolution
+                | Self::SenateJointResolution
+        )
+    }
+
+    /// Check whether the object type is a House or Senate committee report, including conference
+    /// reports and Senate executive reports.
+    pub fn is_report(&self) -> bool {
+        matches!(
+            self,
+            Self::HouseReport
+                | Self::SenateReport
+                | Self::HouseConferenceReport
+                | Self::SenateConferenceReport
+                | Self::SenateExecutiveReport
+        )
+    }
+
+    /// Check whether the object type is a measure, that is, a bill or resolution that can be
+    /// voted on. Reports, amendments, and documents are not measures.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert!(CongObjectType::HouseJointResolution.is_measure());
+    /// assert!(!CongObjectType::SenateReport.is_measure());
+    /// ```
+    pub fn is_measure(&self) -> bool {
+        self.is_bill() || self.is_resolution()
+    }
+
+    /// Get the sequence of stages a document of this type typically passes through.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::CongObjectType;
+    ///
+    /// assert_eq!(&["Filed"], CongObjectType::HouseReport.typical_lifecycle_stages());
+    /// ```
+    pub fn typical_lifecycle_stages(&self) -> &'static [&'static str] {
+        match self {
+            Self::HouseBill | Self::HousePrivateBill | Self::HouseJointResolution => &[
+                "Introduced",
+                "Committee",
+                "Floor Vote",
+                "Senate",
+                "Enrolled",
+                "Signed",
+            ],
+            Self::SenateBill | Self::SenatePrivateBill | Self::SenateJointResolution => &[
+                "Introduced",
+                "Committee",
+                "Floor Vote",
+                "House",
+                "Enrolled",
+                "Signed",
+            ],
+            Self::HouseConcurrentResolution => &[
+                "Introduced",
+                "Committee",
+                "Floor Vote",
+                "Senate",
+                "Agreed To",
+            ],
+            Self::SenateConcurrentResolution => &[
+                "Introduced",
+                "Committee",
+                "Floor Vote",
+                "House",
+                "Agreed To",
+            ],
+            Self::HouseResolution | Self::SenateResolution => {
+                &["Introduced", "Committee", "Floor Vote", "Agreed To"]
+            }
+            Self::HouseConferenceReport | Self::SenateConferenceReport => {
+                &["Filed", "House Vote", "Senate Vote"]
+            }
+            Self::HouseReport | Self::SenateReport | Self::SenateExecutiveReport => &["Filed"],
+            Self::HouseAmendment | Self::SenateAmendment => &["Submitted", "Floor Vote"],
+            Self::HouseDocument | Self::SenateDocument => &["Received", "Referred", "Printed"],
+            Self::HousePrint | Self::SenatePrint => &["Printed"],
+        }
+    }
+}
+
+/// Parse an object type code that starts with its chamber letter, such as `"hr"`, `"s"`, or
+/// `"hjres"`.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::CongObjectType;
+///
+/// assert_eq!(
+///     CongObjectType::HouseJointResolution,
+///     CongObjectType::try_from("hjres").unwrap()
+/// );
+/// assert!(CongObjectType::try_from("jres").is_err());
+/// ```
+impl TryFrom<&str> for CongObjectType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.as_bytes().split_first() {
+            Some((&chamber, abbreviation)) if b"hHsS".contains(&chamber) => {
+                Self::parse(abbreviation, &Chamber::parse(chamber))
+            }
+            _ if value.eq_ignore_ascii_case("erpt") => Ok(Self::SenateExecutiveReport),
+            _ => Err(Error::UnknownCongObjectType),
+        }
+    }
+}
+
+impl Display for CongObjectType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::HouseBill
+                | Self::SenateBill
+                | Self::HousePrivateBill
+                | Self::SenatePrivateBill => "bill",
+                Self::HouseResolution | Self::SenateResolution => "resolution",
+                Self::HouseConcurrentResolution | Self::SenateConcurrentResolution =>
+                    "concurrent-resolution",
+                Self::HouseJointResolution | Self::SenateJointResolution => "joint-resolution",
+                Self::HouseReport
+                | Self::SenateReport
+                | Self::HouseConferenceReport
+                | Self::SenateConferenceReport => "report",
+                Self::SenateExecutiveReport => "executive-report",
+                Self::HouseAmendment | Self::SenateAmendment => "amendment",
+                Self::HouseDocument | Self::SenateDocument => "document",
+                Self::HousePrint | Self::SenatePrint => "print",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typical_lifecycle_stages() {
+        let bill = [
+            "Introduced",
+            "Committee",
+            "Floor Vote",
+            "Senate",
+            "Enrolled",
+            "Signed",
+        ];
+        assert_eq!(bill, CongObjectType::HouseBill.typical_lifecycle_stages());
+        assert_eq!(
+            bill,
+            CongObjectType::HouseJointResolution.typical_lifecycle_stages()
+        );
+        let bill = [
+            "Introduced",
+            "Committee",
+            "Floor Vote",
+            "House",
+            "Enrolled",
+            "Signed",
+        ];
+        assert_eq!(bill, CongObjectType::SenateBill.typical_lifecycle_stages());
+        assert_eq!(
+            bill,
+            CongObjectType::SenateJointResolution.typical_lifecycle_stages()
+        );
+        assert_eq!(
+            [
+                "Introduced",
+                "Committee",
+                "Floor Vote",
+                "Senate",
+                "Agreed To"
+            ],
+            CongObjectType::HouseConcurrentResolution.typical_lifecycle_stages()
+        );
+        assert_eq!(
+            [
+                "Introduced",
+                "Committee",
+                "Floor Vote",
+                "House",
+                "Agreed To"
+            ],
+            CongObjectType::SenateConcurrentResolution.typical_lifecycle_stages()
+        );
+        let resolution = ["Introduced", "Committee", "Floor Vote", "Agreed To"];
+        assert_eq!(
+            resolution,
+            CongObjectType::HouseResolution.typical_lifecycle_stages()
+        );
+        assert_eq!(
+            resolution,
+            CongObjectType::SenateResolution.typical_lifecycle_stages()
+        );
+        assert_eq!(
+            ["Filed"],
+            CongObjectType::HouseReport.typical_lifecycle_stages()
+        );
+        assert_eq!(
+            ["Filed"],
+            CongObjectType::SenateReport.typical_lifecycle_stages()
+        );
+    }
+
+    #[test]
+    fn test_abbreviation_round_trip() {
+        for object_type in CongObjectType::iter() {
+            let abbreviation = object_type.abbreviation().as_bytes();
+            let parsed = CongObjectType::parse(abbreviation, &object_type.chamber()).unwrap();
+            assert_eq!(object_type, parsed);
+        }
+    }
+
+    #[test]
+    fn test_full_name() {
+        assert_eq!("House Report", CongObjectType::HouseReport.full_name());
+        assert_eq!(
+            "Senate Amendment",
+            CongObjectType::SenateAmendment.full_name()
+        );
+        assert_eq!("report", CongObjectType::HouseReport.to_string());
+    }
+
+    #[test]
+    fn test_cong_object_type_try_from() {
+        assert_eq!(
+            Ok(CongObjectType::HouseBill),
+            CongObjectType::try_from("hr")
+        );
+        assert_eq!(
+            Ok(CongObjectType::SenateBill),
+            CongObjectType::try_from("S")
+        );
+        assert_eq!(
+            Ok(CongObjectType::SenateReport),
+            CongObjectType::try_from("srpt")
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            CongObjectType::try_from("xres")
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            CongObjectType::try_from("")
+        );
+        assert_eq!(
+            Ok(CongObjectType::SenateExecutiveReport),
+            CongObjectType::try_from("ERPT")
+        );
+        assert_eq!(
+            Ok(CongObjectType::HouseAmendment),
+            CongObjectType::from_abbreviation("amdt", &Chamber::House)
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            CongObjectType::from_abbreviation("r", &Chamber::Senate)
+        );
+    }
+
+    #[test]
+    fn test_govinfo_docclass() {
+        assert_eq!(Some("BILLS"), CongObjectType::SenateBill.govinfo_docclass());
+        assert_eq!(
+            Some("BILLS"),
+            CongObjectType::HousePrivateBill.govinfo_docclass()
+        );
+        assert_eq!(
+            Some("BILLS"),
+            CongObjectType::HouseConcurrentResolution.govinfo_docclass()
+        );
+        assert_eq!(
+            Some("CRPT"),
+            CongObjectType::SenateExecutiveReport.govinfo_docclass()
+        );
+        assert_eq!(
+            Some("CDOC"),
+            CongObjectType::HouseDocument.govinfo_docclass()
+        );
+        assert_eq!(None, CongObjectType::SenateAmendment.govinfo_docclass());
+    }
+
+    #[test]
+    fn test_is_measure() {
+        let measures = [
+            CongObjectType::SenateBill,
+            CongObjectType::HousePrivateBill,
+            CongObjectType::HouseResolution,
+            CongObjectType::SenateConcurrentResolution,
+            CongObjectType::HouseJointResolution,
+        ];
+        for object_type in measures {
+            assert!(object_type.is_measure(), "{object_type}");
+            assert_eq!("bill", object_type.collection());
+        }
+        let others = [
+            CongObjectType::HouseReport,
+            CongObjectType::SenateExecutiveReport,
+            CongObjectType::SenateAmendment,
+            CongObjectType::HouseDocument,
+        ];
+        for object_type in others {
+            assert!(!object_type.is_measure(), "{object_type}");
+            assert_ne!("bill", object_type.collection());
+        }
+    }
+
+    #[test]
+    fn test_conference_report() {
+        assert_eq!(
+            CongObjectType::HouseConferenceReport,
+            CongObjectType::try_from("hconf").unwrap()
+        );
+        assert_eq!(
+            CongObjectType::SenateConferenceReport,
+            CongObjectType::from_abbreviation("conf", &Chamber::Senate).unwrap()
+        );
+        let conference = CongObjectType::HouseConferenceReport;
+        assert!(conference.is_report());
+        assert!(!conference.is_measure());
+        assert_eq!(Chamber::House, conference.chamber());
+        assert_eq!("congressional-report", conference.collection());
+        assert_eq!("hrpt", conference.code());
+        assert_eq!("report", conference.to_string());
+        assert_eq!("House Conference Report", conference.full_name());
+    }
+
+    #[test]
+    fn test_iter() {
+        let object_types: Vec<_> = CongObjectType::iter().collect();
+        assert!(object_types.is_sorted());
+        assert_eq!(21, object_types.len());
+        for object_type in object_types {
+            let code = format!(
+                "{}{}",
+                object_type.chamber().code(),
+                object_type.abbreviation()
+            );
+            let parsed = match object_type {
+                CongObjectType::SenateExecutiveReport => CongObjectType::try_from("erpt"),
+                _ => CongObjectType::try_from(code.as_str()),
+            };
+            assert_eq!(Ok(object_type), parsed);
+        }
+    }
+
+    #[test]
+    fn test_committee_print() {
+        assert_eq!(
+            CongObjectType::HousePrint,
+            CongObjectType::try_from("hprt").unwrap()
+        );
+        assert_eq!(
+            CongObjectType::SenatePrint,
+            CongObjectType::from_url_segment("senate-print").unwrap()
+        );
+        let print = CongObjectType::SenatePrint;
+        assert!(!print.is_report());
+        assert!(!print.is_measure());
+        assert_eq!(Chamber::Senate, print.chamber());
+        assert_eq!("congressional-document", print.collection());
+        assert_eq!("sprt", print.code());
+        assert_eq!(None, print.govinfo_docclass());
+        assert_eq!(None, print.api_collection());
+        assert_eq!("Senate Committee Print", print.full_name());
+    }
+
+    #[test]
+    fn test_binary_tags_unique() {
+        for object_type in CongObjectType::iter() {
+            assert_eq!(
+                Some(object_type),
+                CongObjectType::from_binary_tag(object_type.binary_tag())
+            );
+        }
+        assert_eq!(None, CongObjectType::from_binary_tag(21));
+    }
+}
@@ -0,0 +1,719 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+use core::ops::RangeInclusive;
+
+use crate::constants::{
+    BASE_URL, FIRST_CONGRESS, FIRST_PRESIDENTIAL_TERM_CONGRESS, PRESIDENTS_BY_CONGRESS,
+};
+#[cfg(feature = "std")]
+use crate::constants::{CURRENT_CONGRESS, CURRENT_YEAR};
+use crate::error::Error;
+use crate::{is_ordinal_suffix, percent_encode, Chamber, Result};
+
+/// A numbered Congress, e.g. the 118th Congress.
+///
+/// Congresses are ordered by number, so they can be compared, sorted, and clamped.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::Congress;
+///
+/// let first = Congress::from_ordinal_str("100th").unwrap();
+/// let last = Congress::from_ordinal_str("118th").unwrap();
+/// assert!(first < last);
+/// assert_eq!(last, Congress::from_year(2026).unwrap().clamp(first, last));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u64"))]
+pub struct Congress(pub(crate) u64);
+
+impl Congress {
+    /// The 1st Congress, which convened in 1789.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!("1st", Congress::FIRST.as_ordinal());
+    /// ```
+    pub const FIRST: Congress = Congress(1);
+
+    /// Get the year the 1st Congress convened, 1789, before which no Congress can be cited.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!(1789, Congress::first_year());
+    /// assert_eq!(Congress::first_year(), Congress::FIRST.start_year());
+    /// ```
+    pub const fn first_year() -> u64 {
+        FIRST_CONGRESS
+    }
+
+    pub(crate) fn new(congress: u64) -> Result<Self> {
+        if Self::is_valid(congress) {
+            Ok(Congress(congress))
+        } else {
+            Err(Error::InvalidCongress)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn is_valid(congress: u64) -> bool {
+        (1..=*CURRENT_CONGRESS).contains(&congress)
+    }
+
+    // without a clock there is no current Congress, so Congresses are bounded only by what
+    // `Citation::to_bytes` can encode
+    #[cfg(not(feature = "std"))]
+    fn is_valid(congress: u64) -> bool {
+        (1..=u64::from(u16::MAX)).contains(&congress)
+    }
+
+    #[cfg(feature = "std")]
+    fn is_valid_year(year: u64) -> bool {
+        (FIRST_CONGRESS..=*CURRENT_YEAR).contains(&year)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn is_valid_year(year: u64) -> bool {
+        year >= FIRST_CONGRESS
+    }
+
+    pub(crate) fn parse(input: &[u8]) -> Result<Self> {
+        if input.is_empty() {
+            return Err(Error::MissingCongress);
+        }
+        let (digits, suffix) = match input
+            .len()
+            .checked_sub(2)
+            .map(|split| input.split_at(split))
+        {
+            Some((digits, suffix)) if is_ordinal_suffix(suffix) => (digits, Some(suffix)),
+            _ => (input, None),
+        };
+        if digits.is_empty() {
+            return Err(Error::InvalidOrdinal);
+        }
+        // leading zeros, as in "018" for the 18th Congress, are accepted
+        let congress = match String::from_utf8(digits.to_vec()) {
+            Ok(s) => s.parse::<u64>()?,
+            Err(e) => return Err(Error::FromUtf8(e)),
+        };
+        // the suffix must be the one the number takes, so "118st" is rejected
+        match suffix {
+            Some(suffix) if !suffix.eq_ignore_ascii_case(ordinal_suffix(congress).as_bytes()) => {
+                Err(Error::InvalidOrdinal)
+            }
+            _ => Self::new(congress),
+        }
+    }
+
+    /// Get the Congress as an ordinal number, e.g. `"118th"`, as used in Congress.gov URLs.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!("118th", Congress::from_year(2024).unwrap().as_ordinal());
+    /// assert_eq!("111th", Congress::from_ordinal_str("111th").unwrap().as_ordinal());
+    /// ```
+    pub fn as_ordinal(&self) -> String {
+        format!("{}{}", self.0, ordinal_suffix(self.0))
+    }
+
+    /// Parse a Congress written as an ordinal number, e.g. `"118th"`, the inverse of its ordinal
+    /// form in Congress.gov URLs.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!(2023, Congress::from_ordinal_str("118th").unwrap().start_year());
+    /// assert_eq!(1789, Congress::from_ordinal_str("1st").unwrap().start_year());
+    /// assert!(Congress::from_ordinal_str("118").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in `Error::InvalidOrdinal` if the input is not a number followed by the ordinal
+    /// suffix it takes (`st`, `nd`, `rd`, or `th`, so `"1th"` is rejected), or in
+    /// `Error::InvalidCongress` if the Congress has not occurred yet.
+    pub fn from_ordinal_str(s: &str) -> Result<Self> {
+        match s
+            .len()
+            .checked_sub(2)
+            .and_then(|split| s.split_at_checked(split))
+        {
+            Some((digits, suffix))
+                if !digits.is_empty()
+                    && digits.bytes().all(|b| b.is_ascii_digit())
+                    && is_ordinal_suffix(suffix.as_bytes()) =>
+            {
+                Self::parse(s.as_bytes())
+            }
+            _ => Err(Error::InvalidOrdinal),
+        }
+    }
+
+    /// Iterate over the Congresses from `start` through `end`, inclusive.
+    ///
+    /// The iterator is empty if `start` is greater than `end`, or if either is not a valid
+    /// Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// let years: Vec<u64> = Congress::range(116, 118).map(|c| c.start_year()).collect();
+    /// assert_eq!(vec![2019, 2021, 2023], years);
+    /// assert_eq!(0, Congress::range(118, 116).count());
+    /// ```
+    pub fn range(start: u64, end: u64) -> impl Iterator<Item = Congress> {
+        let range = (Self::is_valid(start) && Self::is_valid(end)).then_some(start..=end);
+        range.into_iter().flatten().map(Congress)
+    }
+
+    /// Get the URL of a Congress.gov search for all legislation from the Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!(
+    ///     "https://www.congress.gov/search?q=%7B%22congress%22%3A%22118%22%7D&pageSize=250",
+    ///     Congress::from_year(2024).unwrap().legislation_url()
+    /// );
+    /// ```
+    pub fn legislation_url(&self) -> String {
+        Self::search_url(&format!(r#"{{"congress":"{}"}}"#, self.0))
+    }
+
+    /// Get the URL of a Congress.gov search for legislation from the Congress that originated in
+    /// the given chamber.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Chamber, Congress};
+    ///
+    /// assert_eq!(
+    ///     "https://www.congress.gov/search?q=%7B%22congress%22%3A%22118%22%2C%22chamber%22%3A%22Senate%22%7D&pageSize=250",
+    ///     Congress::from_year(2024).unwrap().legislation_url_for_chamber(&Chamber::Senate)
+    /// );
+    /// ```
+    pub fn legislation_url_for_chamber(&self, chamber: &Chamber) -> String {
+        Self::search_url(&format!(
+            r#"{{"congress":"{}","chamber":"{}"}}"#,
+            self.0,
+            chamber.name()
+        ))
+    }
+
+    fn search_url(query: &str) -> String {
+        format!("{BASE_URL}/search?q={}&pageSize=250", percent_encode(query))
+    }
+
+    /// Get the Congress in session during the given calendar year.
+    ///
+    /// This is the inverse of [`Congress::start_year`] and [`Congress::end_year`].
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!(2023, Congress::from_year(2024).unwrap().start_year());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the year is before the first Congress (1789) or after the
+    /// current year.
+    pub fn from_year(year: u64) -> Result<Self> {
+        if Self::is_valid_year(year) {
+            Self::new((year - FIRST_CONGRESS) / 2 + 1)
+        } else {
+            Err(Error::InvalidCongress)
+        }
+    }
+
+    /// Get the Congress in session on the given date, written in the ISO 8601 form `YYYY-MM-DD`.
+    ///
+    /// Unlike [`Congress::from_year`], this accounts for the day each Congress convened, which
+    /// has been January 3 of each odd-numbered year since 1935 and was March 4 before then. A
+    /// date early in an odd-numbered year may fall in the previous Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Congress;
+    ///
+    /// assert_eq!("118th", Congress::from_date("2023-06-15").unwrap().as_ordinal());
+    /// assert_eq!("117th", Congress::from_date("2023-01-02").unwrap().as_ordinal());
+    /// assert_eq!("118th", Congress::from_date("2023-01-03").unwrap().as_ordinal());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in `Error::InvalidDate` if the input is not a valid `YYYY-MM-DD` date, or
+    /// `Error::InvalidCongress` if the date is before the first Congress convened or after the
+    /// current year.
+    pub fn from_date(date: &str) -> Result<Self> {
+        let (year, month, day) = parse_date(date).ok_or(Error::InvalidDate)?;
+        let convened = if year >= 1935 { (1, 3) } else { (3, 4) };
+        if year % 2 == FIRST_CONGRESS % 2 && (month, day) < convened {
+            Self::from_year(year.checked_sub(1).ok_or(Error::InvalidCongress)?)
+        } else {
+            Self::from_year(year)
+        }
+    }
+
+    /// Check whether the Congress is the one now in session.
+    ///
+    /// Requires the `std` feature, which provides the clock used to find the current Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(!Citation::parse("117hr1").unwrap().congress().is_current());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn is_current(&self) -> bool {
+        self.0 == *CURRENT_CONGRESS
+    }
+
+    /// Check whether the Congress has ended, that is, it came before the current Congress.
+    ///
+    /// Requires the `std` feature, which provides the clock used to find the current Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("117hr1").unwrap().congress().is_past());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn is_past(&self) -> bool {
+        self.0 < *CURRENT_CONGRESS
+    }
+
+    /// Get the calendar year in which the Congress began.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(2023, citation.congress().start_year());
+    /// ```
+    pub fn start_year(&self) -> u64 {
+        (self.0 - 1) * 2 + FIRST_CONGRESS
+    }
+
+    /// Get the calendar year in which the Congress ended, one year after it began.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(2024, citation.congress().end_year());
+    /// ```
+    pub fn end_year(&self) -> u64 {
+        self.start_year() + 1
+    }
+
+    /// Get the calendar years the Congress was in session.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert!(citation.congress().year_range().contains(&2024));
+    /// ```
+    pub fn year_range(&self) -> RangeInclusive<u64> {
+        self.start_year()..=self.end_year()
+    }
+
+    /// Get the name or names of the President or Presidents in office during the Congress.
+    ///
+    /// Congresses that saw a change of administration return both Presidents, in order. Returns
+    /// an empty slice for Congresses before the 80th.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("117hr1319").unwrap();
+    /// assert_eq!(
+    ///     &["Donald Trump", "Joe Biden"],
+    ///     citation.congress().presidential_term_overlap()
+    /// );
+    /// ```
+    pub fn presidential_term_overlap(&self) -> &'static [&'static str] {
+        self.0
+            .checked_sub(FIRST_PRESIDENTIAL_TERM_CONGRESS)
+            .and_then(|index| PRESIDENTS_BY_CONGRESS.get(index as usize))
+            .copied()
+            .unwrap_or(&[])
+    }
+}
+
+/// Validate a Congress number, as returned by the Congress.gov API.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{Congress, Error};
+///
+/// assert_eq!("118th", Congress::try_from(118u64).unwrap().as_ordinal());
+/// assert_eq!(Err(Error::InvalidCongress), Congress::try_from(0u64));
+/// ```
+///
+/// # Errors
+///
+/// Returns `Error::InvalidCongress` if the number is zero or after the current Congress.
+impl TryFrom<u64> for Congress {
+    type Error = Error;
+
+    fn try_from(value: u64) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+/// Validate a Congress number. See `TryFrom<u64>`.
+impl TryFrom<u32> for Congress {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        Self::new(value.into())
+    }
+}
+
+impl Display for Congress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Split an ISO 8601 `YYYY-MM-DD` date into its year, month, and day, if it is a real date.
+fn parse_date(date: &str) -> Option<(u64, u64, u64)> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let number = |range: core::ops::Range<usize>| -> Option<u64> {
+        let digits = &date[range];
+        digits
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| digits.parse().ok())?
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if is_leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    (1..=days_in_month)
+        .contains(&day)
+        .then_some((year, month, day))
+}
+
+/// Get the ordinal suffix a number takes, e.g. `"nd"` for 22 and `"th"` for 12.
+fn ordinal_suffix(number: u64) -> &'static str {
+    match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_presidential_term_overlap() {
+        assert_eq!(
+            &["Harry S. Truman"],
+            Congress(80).presidential_term_overlap()
+        );
+        assert_eq!(
+            &["John F. Kennedy", "Lyndon B. Johnson"],
+            Congress(88).presidential_term_overlap()
+        );
+        assert_eq!(&["Barack Obama"], Congress(113).presidential_term_overlap());
+        assert_eq!(
+            &["Joe Biden", "Donald Trump"],
+            Congress(119).presidential_term_overlap()
+        );
+        assert!(Congress(79).presidential_term_overlap().is_empty());
+    }
+
+    #[test]
+    fn test_congress_years() {
+        assert_eq!(1789, Congress(1).start_year());
+        assert_eq!(1790, Congress(1).end_year());
+        assert_eq!(2023, Congress(118).start_year());
+        assert_eq!(2024, Congress(118).end_year());
+        assert_eq!(2023..=2024, Congress(118).year_range());
+    }
+
+    #[test]
+    fn test_congress_from_year() {
+        assert_eq!(Congress(1), Congress::from_year(1789).unwrap());
+        assert_eq!(Congress(1), Congress::from_year(1790).unwrap());
+        assert_eq!(Congress(118), Congress::from_year(2023).unwrap());
+        assert_eq!(Congress(118), Congress::from_year(2024).unwrap());
+        assert_eq!(Err(Error::InvalidCongress), Congress::from_year(1788));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            Congress::from_year(*CURRENT_YEAR + 1)
+        );
+    }
+
+    #[test]
+    fn test_congress_parse_ordinal() {
+        assert_eq!(Congress(118), Congress::parse(b"118th").unwrap());
+        assert_eq!(Congress(1), Congress::parse(b"1st").unwrap());
+        assert_eq!(Congress(118), Congress::parse(b"118").unwrap());
+        assert!(Congress::parse(b"118xx").is_err());
+    }
+
+    #[test]
+    fn test_from_ordinal_str() {
+        assert_eq!(Ok(Congress(1)), Congress::from_ordinal_str("1st"));
+        assert_eq!(Ok(Congress(2)), Congress::from_ordinal_str("2nd"));
+        assert_eq!(Ok(Congress(3)), Congress::from_ordinal_str("3rd"));
+        assert_eq!(Ok(Congress(118)), Congress::from_ordinal_str("118TH"));
+        assert_eq!(Err(Error::InvalidOrdinal), Congress::from_ordinal_str("th"));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            Congress::from_ordinal_str("999th")
+        );
+        assert_eq!(
+            Err(Error::InvalidOrdinal),
+            Congress::from_ordinal_str("118")
+        );
+        assert_eq!(
+            Err(Error::InvalidOrdinal),
+            Congress::from_ordinal_str("abc")
+        );
+        assert!(Congress::from_ordinal_str("1x8th").is_err());
+    }
+
+    #[test]
+    fn test_as_ordinal() {
+        let ordinals = [
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (102, "102nd"),
+            (111, "111th"),
+            (113, "113th"),
+            (118, "118th"),
+        ];
+        for (congress, ordinal) in ordinals {
+            assert_eq!(ordinal, Congress(congress).as_ordinal());
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        let congresses: Vec<_> = Congress::range(100, 118).collect();
+        assert_eq!(19, congresses.len());
+        assert_eq!(Some(&Congress(100)), congresses.first());
+        assert_eq!(Some(&Congress(118)), congresses.last());
+        assert_eq!(vec![Congress(5)], Congress::range(5, 5).collect::<Vec<_>>());
+        assert_eq!(0, Congress::range(0, 5).count());
+        #[cfg(feature = "std")]
+        assert_eq!(0, Congress::range(118, *CURRENT_CONGRESS + 1).count());
+    }
+
+    #[test]
+    fn test_legislation_url() {
+        assert_eq!(
+            "https://www.congress.gov/search?q=%7B%22congress%22%3A%2211%22%7D&pageSize=250",
+            Congress(11).legislation_url()
+        );
+        assert!(Congress(11)
+            .legislation_url_for_chamber(&Chamber::House)
+            .contains("%22chamber%22%3A%22House%22"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Congress(99) < Congress(100));
+        assert_eq!(
+            Congress(100),
+            Congress(5).clamp(Congress(100), Congress(118))
+        );
+        let mut congresses = vec![Congress(118), Congress(3), Congress(110)];
+        congresses.sort();
+        assert_eq!(vec![Congress(3), Congress(110), Congress(118)], congresses);
+        assert_eq!(Some(Congress(118)), congresses.into_iter().max());
+    }
+
+    #[test]
+    fn test_try_from_integer() {
+        assert_eq!(Ok(Congress(118)), Congress::try_from(118u64));
+        assert_eq!(Ok(Congress(1)), Congress::try_from(1u32));
+        assert_eq!(Err(Error::InvalidCongress), Congress::try_from(0u64));
+        assert_eq!(Err(Error::InvalidCongress), Congress::try_from(0u32));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            Congress::try_from(*CURRENT_CONGRESS + 1)
+        );
+    }
+
+    #[test]
+    fn test_first() {
+        assert_eq!(Congress(1), Congress::FIRST);
+        assert_eq!(
+            Ok(Congress::FIRST),
+            Congress::from_year(Congress::first_year())
+        );
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            Congress::from_year(Congress::first_year() - 1)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_current_and_is_past() {
+        let current = Congress(*CURRENT_CONGRESS);
+        assert!(current.is_current());
+        assert!(!current.is_past());
+        let previous = Congress(*CURRENT_CONGRESS - 1);
+        assert!(!previous.is_current());
+        assert!(previous.is_past());
+        assert!(Congress::FIRST.is_past());
+    }
+
+    #[test]
+    fn test_congress_parse_missing() {
+        assert_eq!(Err(Error::MissingCongress), Congress::parse(b""));
+        assert_eq!(Err(Error::InvalidOrdinal), Congress::parse(b"th"));
+    }
+
+    #[test]
+    fn test_from_date() {
+        assert_eq!(Ok(Congress(118)), Congress::from_date("2023-06-15"));
+        assert_eq!(Ok(Congress(118)), Congress::from_date("2024-12-31"));
+        assert_eq!(Ok(Congress(117)), Congress::from_date("2023-01-02"));
+        assert_eq!(Ok(Congress(118)), Congress::from_date("2023-01-03"));
+        assert_eq!(Ok(Congress(73)), Congress::from_date("1935-01-02"));
+        assert_eq!(Ok(Congress(74)), Congress::from_date("1935-01-03"));
+        assert_eq!(Ok(Congress(72)), Congress::from_date("1933-03-03"));
+        assert_eq!(Ok(Congress(73)), Congress::from_date("1933-03-04"));
+        assert_eq!(Ok(Congress(1)), Congress::from_date("1789-03-04"));
+        assert_eq!(Ok(Congress(2)), Congress::from_date("1792-02-29"));
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            Congress::from_date("1789-03-03")
+        );
+    }
+
+    #[test]
+    fn test_from_date_errors() {
+        for date in [
+            "2023-6-15",
+            "2023/06/15",
+            "2023-13-01",
+            "2023-00-10",
+            "2023-02-29",
+            "2023-04-31",
+            "2023-06-00",
+            "2023-06-+5",
+            "20230615",
+            "",
+        ] {
+            assert_eq!(Err(Error::InvalidDate), Congress::from_date(date), "{date}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_congress() {
+        assert_eq!(
+            Congress(118),
+            serde_json::from_str::<Congress>("118").unwrap()
+        );
+        assert_eq!("118", serde_json::to_string(&Congress(118)).unwrap());
+        assert!(serde_json::from_str::<Congress>("0").is_err());
+        assert!(serde_json::from_str::<Congress>("-1").is_err());
+        #[cfg(feature = "std")]
+        assert!(serde_json::from_str::<Congress>(&(*CURRENT_CONGRESS + 1).to_string()).is_err());
+    }
+
+    #[test]
+    fn test_congress_parse_mismatched_suffix() {
+        assert_eq!(Ok(Congress(118)), Congress::parse(b"118TH"));
+        assert_eq!(Ok(Congress(111)), Congress::parse(b"111th"));
+        assert_eq!(Ok(Congress(102)), Congress::parse(b"102nd"));
+        for input in [b"118st", b"118nd", b"111st", b"112nd", b"113rd", b"101th"] {
+            assert_eq!(
+                Err(Error::InvalidOrdinal),
+                Congress::parse(input),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_ordinal_str_mismatched_suffix() {
+        assert_eq!(Ok(Congress(1)), Congress::from_ordinal_str("1st"));
+        assert_eq!(Ok(Congress(23)), Congress::from_ordinal_str("23rd"));
+        for input in [
+            "1th", "118st", "2rd", "3nd", "12nd", "21th", "+118th", " 118th",
+        ] {
+            assert_eq!(
+                Err(Error::InvalidOrdinal),
+                Congress::from_ordinal_str(input),
+                "{input}"
+            );
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn test_is_valid_without_clock() {
+        assert_eq!(Ok(Congress(65535)), Congress::new(65535));
+        assert_eq!(Err(Error::InvalidCongress), Congress::new(65536));
+        assert_eq!(Err(Error::InvalidCongress), Congress::new(0));
+    }
+}
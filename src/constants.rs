@@ -1,22 +1,205 @@
+#[cfg(feature = "std")]
 use std::sync::LazyLock;
+#[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
-const FIRST_CONGRESS: u64 = 1789;
-static CURRENT_YEAR: LazyLock<u64> = LazyLock::new(|| {
-    SystemTime::now()
+pub const FIRST_CONGRESS: u64 = 1789;
+#[cfg(feature = "std")]
+pub static CURRENT_YEAR: LazyLock<u64> = LazyLock::new(|| {
+    let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap() // TODO: change to expect?
-        .as_secs()
-        / 31_536_000 // seconds in year
-        + 1970 // UNIX_EPOCH year
+        .as_secs();
+    year_from_unix_seconds(now)
 });
+#[cfg(feature = "std")]
 pub static CURRENT_CONGRESS: LazyLock<u64> =
     LazyLock::new(|| (*CURRENT_YEAR - FIRST_CONGRESS) / 2 + 1);
+
+/// Get the Gregorian calendar year of a time given in seconds since the Unix epoch.
+///
+/// Dividing by a fixed number of seconds per year drifts by a day every leap year, so this
+/// counts whole 400-year cycles from March 1 of year 0, placing each leap day at the end of its
+/// year.
+#[cfg(feature = "std")]
+fn year_from_unix_seconds(seconds: u64) -> u64 {
+    const DAYS_FROM_MARCH_0000_TO_EPOCH: u64 = 719_468;
+    const DAYS_IN_400_YEARS: u64 = 146_097;
+    let days = seconds / 86_400 + DAYS_FROM_MARCH_0000_TO_EPOCH;
+    let cycle = days / DAYS_IN_400_YEARS;
+    let day_of_cycle = days % DAYS_IN_400_YEARS;
+    let year_of_cycle = (day_of_cycle - day_of_cycle / 1_460 + day_of_cycle / 36_524
+        - day_of_cycle / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_cycle - (365 * year_of_cycle + year_of_cycle / 4 - year_of_cycle / 100);
+    // These years begin on March 1, so January and February belong to the next calendar year.
+    let in_january_or_february = day_of_year >= 306;
+    cycle * 400 + year_of_cycle + u64::from(in_january_or_february)
+}
+
 pub const BASE_URL: &str = "https://www.congress.gov";
+pub const API_BASE_URL: &str = "https://api.congress.gov/v3";
+pub const GOVINFO_BASE_URL: &str = "https://www.govinfo.gov";
+pub const PROPUBLICA_API_BASE_URL: &str = "https://api.propublica.org/congress/v1";
+pub const GOVTRACK_BASE_URL: &str = "https://www.govtrack.us";
 
+/// Suffixes that may follow a Congress number written as an ordinal, e.g. `118th`.
+pub const ORDINAL_SUFFIXES: [&[u8]; 4] = [b"st", b"nd", b"rd", b"th"];
+
+/// Recognized bill text version codes, kept sorted so membership can be checked with a binary
+/// search.
 pub const BILL_VERSIONS: [&[u8]; 37] = [
     b"as", b"ash", b"ath", b"ats", b"cdh", b"cds", b"cph", b"cps", b"eah", b"eas", b"eh", b"enr",
     b"es", b"fph", b"fps", b"hds", b"ih", b"iph", b"ips", b"is", b"lth", b"lts", b"pap", b"pcs",
     b"pp", b"rch", b"rcs", b"rds", b"rfh", b"rfs", b"rh", b"rhuc", b"rih", b"rs", b"rth", b"rts",
     b"sc",
 ];
+
+/// Long-form aliases for bill version codes, with the code each stands for in a House and in a
+/// Senate document.
+pub const BILL_VERSION_ALIASES: [(&[u8], &[u8], &[u8]); 5] = [
+    (b"enacted", b"enr", b"enr"),
+    (b"enrolled", b"enr", b"enr"),
+    (b"engrossed", b"eh", b"es"),
+    (b"introduced", b"ih", b"is"),
+    (b"reported", b"rh", b"rs"),
+];
+
+/// Approximate highest document number issued in each Congress, keyed by Congress number.
+///
+/// The columns are, in order: S., H.R., S.Res., H.Res., S.Con.Res., H.Con.Res., S.J.Res.,
+/// H.J.Res., H.Rept., and S.Rept. Private bills are counted with public bills and conference
+/// reports with committee reports, as in `Citation::estimated_max_bill_number`, which maps each
+/// `CongObjectType` to its column.
+pub const MAX_DOCUMENT_NUMBERS: [(u64, [u32; 10]); 9] = [
+    (110, [3731, 7321, 735, 1586, 108, 444, 49, 104, 931, 544]),
+    (111, [4059, 6568, 714, 1782, 78, 336, 42, 105, 712, 372]),
+    (112, [3676, 6729, 628, 825, 60, 147, 51, 122, 738, 264]),
+    (113, [3021, 5859, 601, 788, 44, 123, 44, 131, 722, 311]),
+    (114, [3554, 6514, 633, 951, 57, 183, 41, 104, 905, 384]),
+    (115, [3786, 7401, 752, 1176, 56, 149, 71, 148, 1112, 342]),
+    (116, [5094, 9057, 800, 1285, 52, 124, 88, 112, 700, 331]),
+    (117, [5361, 9709, 889, 1549, 53, 123, 69, 102, 654, 312]),
+    (118, [5600, 10545, 950, 1600, 46, 139, 123, 229, 720, 290]),
+];
+
+/// The first Congress covered by `PRESIDENTS_BY_CONGRESS`.
+pub const FIRST_PRESIDENTIAL_TERM_CONGRESS: u64 = 80;
+
+/// The President or Presidents in office during each Congress, starting with the 80th.
+pub const PRESIDENTS_BY_CONGRESS: [&[&str]; 40] = [
+    &["Harry S. Truman"],
+    &["Harry S. Truman"],
+    &["Harry S. Truman"],
+    &["Harry S. Truman", "Dwight D. Eisenhower"],
+    &["Dwight D. Eisenhower"],
+    &["Dwight D. Eisenhower"],
+    &["Dwight D. Eisenhower"],
+    &["Dwight D. Eisenhower", "John F. Kennedy"],
+    &["John F. Kennedy", "Lyndon B. Johnson"],
+    &["Lyndon B. Johnson"],
+    &["Lyndon B. Johnson"],
+    &["Lyndon B. Johnson", "Richard Nixon"],
+    &["Richard Nixon"],
+    &["Richard Nixon", "Gerald Ford"],
+    &["Gerald Ford"],
+    &["Gerald Ford", "Jimmy Carter"],
+    &["Jimmy Carter"],
+    &["Jimmy Carter", "Ronald Reagan"],
+    &["Ronald Reagan"],
+    &["Ronald Reagan"],
+    &["Ronald Reagan"],
+    &["Ronald Reagan", "George H. W. Bush"],
+    &["George H. W. Bush"],
+    &["George H. W. Bush", "Bill Clinton"],
+    &["Bill Clinton"],
+    &["Bill Clinton"],
+    &["Bill Clinton"],
+    &["Bill Clinton", "George W. Bush"],
+    &["George W. Bush"],
+    &["George W. Bush"],
+    &["George W. Bush"],
+    &["George W. Bush", "Barack Obama"],
+    &["Barack Obama"],
+    &["Barack Obama"],
+    &["Barack Obama"],
+    &["Barack Obama", "Donald Trump"],
+    &["Donald Trump"],
+    &["Donald Trump", "Joe Biden"],
+    &["Joe Biden"],
+    &["Joe Biden", "Donald Trump"],
+];
+
+/// Every recognized bill text version code with its description, sorted by code.
+///
+/// This is the data behind `Version::description`, suitable for listing the versions in a user
+/// interface.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::BILL_VERSIONS_DESCRIPTIONS;
+///
+/// assert!(BILL_VERSIONS_DESCRIPTIONS.contains(&("ih", "Introduced in House")));
+/// assert_eq!(37, BILL_VERSIONS_DESCRIPTIONS.len());
+/// ```
+pub const BILL_VERSIONS_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("as", "Amendment Ordered to be Printed"),
+    ("ash", "Additional Sponsors House"),
+    ("ath", "Agreed to House"),
+    ("ats", "Agreed to Senate"),
+    ("cdh", "Committee Discharged House"),
+    ("cds", "Committee Discharged Senate"),
+    ("cph", "Considered and Passed House"),
+    ("cps", "Considered and Passed Senate"),
+    ("eah", "Engrossed Amendment House"),
+    ("eas", "Engrossed Amendment Senate"),
+    ("eh", "Engrossed in House"),
+    ("enr", "Enrolled Bill"),
+    ("es", "Engrossed in Senate"),
+    ("fph", "Failed Passage House"),
+    ("fps", "Failed Passage Senate"),
+    ("hds", "Held at Desk Senate"),
+    ("ih", "Introduced in House"),
+    ("iph", "Indefinitely Postponed in House"),
+    ("ips", "Indefinitely Postponed in Senate"),
+    ("is", "Introduced in Senate"),
+    ("lth", "Laid on Table in House"),
+    ("lts", "Laid on Table in Senate"),
+    ("pap", "Printed as Passed"),
+    ("pcs", "Placed on Calendar Senate"),
+    ("pp", "Public Print"),
+    ("rch", "Reference Change House"),
+    ("rcs", "Reference Change Senate"),
+    ("rds", "Received in Senate"),
+    ("rfh", "Referred in House"),
+    ("rfs", "Referred in Senate"),
+    ("rh", "Reported in House"),
+    ("rhuc", "Returned to the House by Unanimous Consent"),
+    ("rih", "Referral Instructions House"),
+    ("rs", "Reported in Senate"),
+    ("rth", "Referred to Committee House"),
+    ("rts", "Referred to Committee Senate"),
+    ("sc", "Sponsor Change"),
+];
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_year_from_unix_seconds() {
+        let day = 86_400;
+        assert_eq!(1970, year_from_unix_seconds(0));
+        // 2024-12-31T23:59:59Z, late in a leap year
+        assert_eq!(2024, year_from_unix_seconds(1_735_689_599));
+        assert_eq!(2025, year_from_unix_seconds(1_735_689_600));
+        // 2000-02-29 and 2000-03-01, either side of a leap day
+        assert_eq!(2000, year_from_unix_seconds(11_016 * day));
+        assert_eq!(2000, year_from_unix_seconds(11_017 * day));
+        // 2100-01-01, after a century year that is not a leap year
+        assert_eq!(2099, year_from_unix_seconds(4_102_444_799));
+        assert_eq!(2100, year_from_unix_seconds(4_102_444_800));
+    }
+}
@@ -1,26 +1,121 @@
-use std::{fmt::Display, num::ParseIntError, string::FromUtf8Error};
+use alloc::boxed::Box;
+use alloc::string::{FromUtf8Error, String};
+use core::{fmt::Display, num::ParseIntError};
 
-#[derive(Debug)]
+/// The errors that can occur while parsing or converting a citation.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
+    /// The citation contained bytes that are not valid UTF-8.
     FromUtf8(FromUtf8Error),
+    /// A number in the citation could not be parsed.
     ParseInt(ParseIntError),
+    /// The amendment and the measure it amends are from different Congresses.
+    AmendmentCongressMismatch,
+    /// The object type does not belong to the given chamber.
+    ChamberMismatch,
+    /// The chamber is not a recognized chamber of Congress.
+    InvalidChamber,
+    /// The document number is zero or missing.
+    InvalidBillNumber,
+    /// The bill version is not a recognized version code.
     InvalidBillVersion,
+    /// The bytes are not a citation in the binary layout of `Citation::to_bytes`.
+    InvalidBytes,
+    /// The Congress is zero or after the current Congress.
     InvalidCongress,
+    /// The date is not a valid calendar date in the ISO 8601 form `YYYY-MM-DD`.
+    InvalidDate,
+    /// The Congress is not a number followed by the ordinal suffix it takes, as in `118th`.
+    InvalidOrdinal,
+    /// The URL is not a recognized Congress.gov document URL.
+    InvalidUrl,
+    /// The citation does not begin with a Congress number.
+    MissingCongress,
+    /// The citation has no bill version where one is required.
+    MissingBillVersion,
+    /// A hyphen was expected between the Congress and the document number.
+    MissingHyphen,
+    /// A required part of the citation was never supplied.
+    MissingField(&'static str),
+    /// The citation is valid but does not cite an amendment.
+    NotAnAmendment,
+    /// The citation is valid but not written in its canonical form, as required by
+    /// `parse_strict`.
+    NotCanonical,
+    /// The object type is unknown or not supported for the requested operation.
     UnknownCongObjectType,
+    /// An error in one part of a parsed citation, with the byte offset and text of that part.
+    ///
+    /// Its message names only the offending part; the underlying error is its `source`.
+    ParseContext {
+        /// The byte offset in the input where the offending part begins.
+        position: usize,
+        /// The offending part of the input.
+        fragment: String,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Get the underlying error, looking past any [`Error::ParseContext`].
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Citation, Error};
+    ///
+    /// let error = Citation::parse("118xyz999").unwrap_err();
+    /// assert_eq!(&Error::UnknownCongObjectType, error.root_cause());
+    /// ```
+    pub fn root_cause(&self) -> &Error {
+        match self {
+            Self::ParseContext { source, .. } => source.root_cause(),
+            _ => self,
+        }
+    }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::FromUtf8(e) => Display::fmt(e, f),
             Self::ParseInt(e) => Display::fmt(e, f),
+            Self::AmendmentCongressMismatch => {
+                f.write_str("amendment and amended measure are from different congresses")
+            }
+            Self::ChamberMismatch => {
+                f.write_str("object type does not belong to the given chamber")
+            }
+            Self::InvalidChamber => f.write_str("not a recognized chamber of Congress"),
+            Self::InvalidBillNumber => f.write_str("bill number must be a positive integer"),
             Self::InvalidBillVersion => f.write_str("not a valid bill version"),
+            Self::InvalidBytes => f.write_str("not a binary citation"),
             Self::InvalidCongress => {
-                f.write_str("congress number in citation has not occurred yet")
+                f.write_str("congress number must be between 1 and the current Congress")
             }
+            Self::InvalidDate => f.write_str("not a valid date of the form YYYY-MM-DD"),
+            Self::InvalidOrdinal => {
+                f.write_str("congress must be a number followed by its ordinal suffix")
+            }
+            Self::InvalidUrl => f.write_str("not a recognized Congress.gov document URL"),
+            Self::MissingCongress => f.write_str("congress number is required"),
+            Self::MissingBillVersion => f.write_str("citation does not specify a bill version"),
+            Self::MissingHyphen => {
+                f.write_str("expected a hyphen between the congress and document number")
+            }
+            Self::MissingField(field) => write!(f, "citation is missing its {field}"),
+            Self::NotAnAmendment => f.write_str("citation is not an amendment"),
+            Self::NotCanonical => f.write_str("citation is not in its canonical form"),
             Self::UnknownCongObjectType => {
                 f.write_str("unknown or unsupported congressional object type")
             }
+            Self::ParseContext {
+                position, fragment, ..
+            } => write!(
+                f,
+                "invalid citation part '{fragment}' at position {position}"
+            ),
         }
     }
 }
@@ -37,4 +132,72 @@ impl From<ParseIntError> for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::FromUtf8(e) => Some(e),
+            Self::ParseInt(e) => Some(e),
+            Self::ParseContext { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::error::Error as _;
+
+    use super::*;
+
+    #[test]
+    fn test_source() {
+        let inner = "x".parse::<usize>().unwrap_err();
+        let error = Error::from(inner.clone());
+        let source = error.source().unwrap();
+        assert_eq!(Some(&inner), source.downcast_ref::<ParseIntError>());
+
+        let error = Error::from(String::from_utf8(vec![0xff]).unwrap_err());
+        assert!(error.source().unwrap().is::<FromUtf8Error>());
+
+        assert!(Error::InvalidCongress.source().is_none());
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let error = crate::Citation::parse("118hr99999999999999999999999").unwrap_err();
+        let context = error.source().unwrap();
+        assert!(context.is::<Error>());
+        assert!(context.source().unwrap().is::<ParseIntError>());
+    }
+
+    #[test]
+    fn test_parse_context_message() {
+        let error = crate::Citation::parse("118xyz999").unwrap_err();
+        assert_eq!(
+            "invalid citation part 'xyz' at position 3",
+            error.to_string()
+        );
+        assert_eq!(
+            "unknown or unsupported congressional object type",
+            error.source().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_congress_messages() {
+        assert_eq!(
+            "congress number must be between 1 and the current Congress",
+            crate::Citation::parse("0hr1")
+                .unwrap_err()
+                .source()
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "congress must be a number followed by its ordinal suffix",
+            crate::Congress::from_ordinal_str("118st")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
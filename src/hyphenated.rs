@@ -0,0 +1,229 @@
+//! Citations of the form `<PREFIX><CONGRESS>-<NUMBER>`, such as public laws, treaty documents,
+//! and nominations, which differ only in their prefixes and Congress.gov URLs.
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::constants::BASE_URL;
+use crate::error::Error;
+use crate::{parse_hyphenated, Congress, Result};
+
+/// Define a citation type numbered per Congress and cited as `<PREFIX><CONGRESS>-<NUMBER>`.
+///
+/// The first prefix is the canonical one, used by `Display`; any others are accepted aliases.
+/// `url` is the Congress.gov path after the domain, with placeholders for the ordinal Congress
+/// and the number, and `example_url` is that URL for the 118th Congress and `example` number.
+macro_rules! hyphenated_citation {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            noun: $noun:literal,
+            prefixes: [$prefix:literal $(, $alias:literal)*],
+            url: $url:literal,
+            example: $example:literal,
+            example_url: $example_url:literal,
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name {
+            congress: Congress,
+            number: usize,
+        }
+
+        impl $name {
+            #[doc = concat!("Parse a ", $noun, " citation.")]
+            ///
+            #[doc = concat!(
+                "The `", $prefix, "` prefix is accepted in any case",
+                $(", as is `", $alias, "`",)*
+                "."
+            )]
+            ///
+            /// Example
+            ///
+            /// ```rust
+            #[doc = concat!("use capitol::", stringify!($name), ";")]
+            ///
+            #[doc = concat!(
+                "let citation = ", stringify!($name), "::parse(\"",
+                $prefix, "118-", $example, "\").unwrap();"
+            )]
+            #[doc = concat!("assert_eq!(", $example, ", citation.number());")]
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Will result in an error if the prefix is not recognized, if the hyphen between the
+            /// Congress and the number is missing, if either number can't be parsed or the number
+            /// is zero, or if the Congress is greater than the current Congress.
+            pub fn parse(input: &str) -> Result<Self> {
+                let (congress, number) = parse_hyphenated(input, &[$prefix $(, $alias)*])?;
+
+                Ok($name { congress, number })
+            }
+
+            #[doc = concat!("Get the ", $noun, "'s Congress.")]
+            pub fn congress(&self) -> &Congress {
+                &self.congress
+            }
+
+            #[doc = concat!("Get the ", $noun, " number.")]
+            pub fn number(&self) -> usize {
+                self.number
+            }
+
+            #[doc = concat!("Converts a `", stringify!($name), "` to a URL on Congress.gov.")]
+            ///
+            /// Example
+            ///
+            /// ```rust
+            #[doc = concat!("use capitol::", stringify!($name), ";")]
+            ///
+            #[doc = concat!(
+                "let url = \"", $prefix, "118-", $example, "\".parse::<",
+                stringify!($name), ">().unwrap().to_url();"
+            )]
+            #[doc = concat!("assert_eq!(\"", $example_url, "\", url);")]
+            /// ```
+            pub fn to_url(&self) -> String {
+                format!(
+                    concat!("{}/", $url),
+                    BASE_URL,
+                    self.congress.as_ordinal(),
+                    self.number
+                )
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, concat!($prefix, "{}-{}"), self.congress, self.number)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+            fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+    };
+}
+
+hyphenated_citation! {
+    /// Represents a Public Law citation, e.g. `pl118-5` for Public Law 118-5.
+    ///
+    /// Public laws are the enacted versions of bills. They are numbered per Congress and cited
+    /// with a hyphen between the Congress and the law number.
+    PublicLawCitation {
+        noun: "public law",
+        prefixes: ["pl", "publaw"],
+        url: "public-law/{}-congress/public-law-{}",
+        example: 5,
+        example_url: "https://www.congress.gov/public-law/118th-congress/public-law-5",
+    }
+}
+
+hyphenated_citation! {
+    /// Represents a Senate Treaty Document citation, e.g. `td118-1` for Treaty Document 118-1.
+    ///
+    /// Treaty documents are numbered per Congress and cited with a hyphen between the Congress
+    /// and the document number.
+    TreatyCitation {
+        noun: "treaty document",
+        prefixes: ["td"],
+        url: "treaty-document/{}-congress/{}",
+        example: 1,
+        example_url: "https://www.congress.gov/treaty-document/118th-congress/1",
+    }
+}
+
+hyphenated_citation! {
+    /// Represents a Senate nomination citation, e.g. `pn118-1` for Presidential Nomination 118-1.
+    ///
+    /// Nominations are numbered per Congress and cited with a hyphen between the Congress and
+    /// the nomination number.
+    NominationCitation {
+        noun: "nomination",
+        prefixes: ["pn"],
+        url: "nomination/{}-congress/{}",
+        example: 1,
+        example_url: "https://www.congress.gov/nomination/118th-congress/1",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_prefixes() {
+        let expected = PublicLawCitation {
+            congress: Congress(118),
+            number: 5,
+        };
+        for input in ["pl118-5", "publaw118-5", "PL118-5", "PubLaw118-5"] {
+            assert_eq!(expected, input.parse().unwrap());
+        }
+        let expected = TreatyCitation {
+            congress: Congress(118),
+            number: 1,
+        };
+        assert_eq!(expected, "TD118-1".parse().unwrap());
+        let expected = NominationCitation {
+            congress: Congress(118),
+            number: 1,
+        };
+        assert_eq!(expected, "PN118-1".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(Error::MissingHyphen),
+            PublicLawCitation::parse("pl1185")
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            PublicLawCitation::parse("law118-5")
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            TreatyCitation::parse("pl118-1")
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            NominationCitation::parse("td118-1")
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            PublicLawCitation::parse("pl999-5")
+        );
+    }
+
+    #[test]
+    fn test_to_url_and_display() {
+        let citation = "publaw117-328".parse::<PublicLawCitation>().unwrap();
+        assert_eq!(
+            "https://www.congress.gov/public-law/117th-congress/public-law-328",
+            citation.to_url()
+        );
+        assert_eq!("pl117-328", citation.to_string());
+        let citation = "td117-3".parse::<TreatyCitation>().unwrap();
+        assert_eq!(
+            "https://www.congress.gov/treaty-document/117th-congress/3",
+            citation.to_url()
+        );
+        assert_eq!("td117-3", citation.to_string());
+        let citation = "pn117-3".parse::<NominationCitation>().unwrap();
+        assert_eq!(
+            "https://www.congress.gov/nomination/117th-congress/3",
+            citation.to_url()
+        );
+        assert_eq!("pn117-3", citation.to_string());
+    }
+}
@@ -0,0 +1,119 @@
+//! A tokenizer for legislative citations built with Winnow, used by [`crate::Citation::parse`]
+//! in place of the hand-written tokenizer when the `winnow` feature is enabled.
+
+use winnow::ascii::{alpha0, digit0};
+use winnow::combinator::opt;
+use winnow::stream::LocatingSlice;
+use winnow::token::{one_of, take, take_while};
+use winnow::{ModalResult, Parser};
+
+use crate::{is_ordinal_suffix, CiteBytes, CiteSpans};
+
+type Stream<'i> = LocatingSlice<&'i [u8]>;
+
+/// The Congress digits and any ordinal suffix, as in "118th".
+fn congress<'i>(input: &mut Stream<'i>) -> ModalResult<&'i [u8]> {
+    opt((
+        take_while(1.., b'0'..=b'9'),
+        opt(take(2usize).verify(is_ordinal_suffix)),
+    ))
+    .take()
+    .parse_next(input)
+}
+
+/// An optional hyphen separating the parts of a citation, as in "118-HR-815".
+fn separator(input: &mut Stream<'_>) -> ModalResult<()> {
+    opt(b'-').void().parse_next(input)
+}
+
+fn citation(input: &mut Stream<'_>) -> ModalResult<(CiteBytes, CiteSpans)> {
+    let (congress, congress_span) = congress.with_span().parse_next(input)?;
+    separator(input)?;
+    let ((chamber, object_type), object_type_span) =
+        (opt(one_of([b'h', b'H', b's', b'S'])), alpha0)
+            .with_span()
+            .parse_next(input)?;
+    separator(input)?;
+    let (number, number_span) = digit0.with_span().parse_next(input)?;
+    separator(input)?;
+    let (ver, ver_span) = alpha0.with_span().parse_next(input)?;
+
+    let parts = CiteBytes {
+        congress: congress.to_vec(),
+        chamber: chamber.unwrap_or_default(),
+        object_type: object_type.to_vec(),
+        number: number.to_vec(),
+        ver: (!ver.is_empty()).then(|| ver.to_vec()),
+    };
+    let spans = CiteSpans {
+        congress: congress_span,
+        object_type: object_type_span,
+        number: number_span,
+        ver: ver_span,
+    };
+    Ok((parts, spans))
+}
+
+/// Split a citation into its parts, recording where in the input each part was found.
+///
+/// Like the hand-written tokenizer, this never fails: missing parts are left empty for
+/// [`crate::Citation::parse`] to reject, and trailing input is ignored.
+pub(crate) fn tokenize_with_spans(input: &str) -> (CiteBytes, CiteSpans) {
+    citation
+        .parse_next(&mut LocatingSlice::new(input.as_bytes()))
+        .expect("every part of a citation is optional")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Citation;
+
+    #[test]
+    fn test_agrees_with_hand_written_tokenizer() {
+        let inputs = [
+            "118hr815",
+            "118hr8070ih",
+            "118s5",
+            "118sres12ats",
+            "118hconres3",
+            "118hjres7enr",
+            "118hrpt17",
+            "118samdt1",
+            "118hdoc2",
+            "118th-congress",
+            "118th hr 1",
+            "118-HR-815",
+            "118-S-5-IS",
+            "118ND-hR-1",
+            "118hr815ih-extra",
+            "118xyz999",
+            "118hr",
+            "hr815",
+            "999hr1",
+            "110hr1",
+            "018hr815",
+            "0hr1",
+            "-",
+            "",
+        ];
+        for input in inputs {
+            assert_eq!(
+                Citation::tokenize_with_spans(input),
+                tokenize_with_spans(input),
+                "tokenizers disagree on {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_spans() {
+        let (parts, spans) = tokenize_with_spans("118th-HR-815-IH");
+        assert_eq!(b"118th".to_vec(), parts.congress);
+        assert_eq!(b'H', parts.chamber);
+        assert_eq!(0..5, spans.congress);
+        assert_eq!(6..8, spans.object_type);
+        assert_eq!(9..12, spans.number);
+        assert_eq!(13..15, spans.ver);
+    }
+}
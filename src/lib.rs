@@ -1,4 +1,4 @@
-// TODO: getters for Citation
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 //! Parse citations of United States Congress legislative documents and convert them to
 //! Congress.gov URLs.
@@ -10,144 +10,375 @@
 //! Legislative citations generally follow the form `<CONGRESS><CONGRESSIONAL_OBJECT_TYPE><NUMBER>`.
 //! Measures (bills) can specify a version of the text of the bill with a two-to-three letter
 //! string at the end of the citation.
+//!
+//! The parts of a parsed citation are available as the [`Congress`], [`Chamber`], and
+//! [`CongObjectType`] types, which can be matched on directly:
+//!
+//! ```rust
+//! use capitol::{Chamber, Citation, CongObjectType};
+//!
+//! let citation = Citation::parse("118hr815").unwrap();
+//! assert_eq!(&Chamber::House, citation.chamber());
+//! assert!(matches!(citation.object_type(), &CongObjectType::HouseBill));
+//! ```
+//!
+//! Public laws, Senate treaty documents, and nominations are cited with a hyphen between the
+//! Congress and the number, in the forms `pl<CONGRESS>-<NUMBER>`, `td<CONGRESS>-<NUMBER>`, and
+//! `pn<CONGRESS>-<NUMBER>`. They are parsed with `PublicLawCitation::parse`,
+//! `TreatyCitation::parse`, and `NominationCitation::parse`. Statutes at Large are cited by
+//! volume and page, as in `stat118-5`, and parsed with `StatuteCitation::parse`. Amendments can
+//! also be parsed on their own with `Amendment::parse`, which can record the amended measure.
+//!
+//! Enabling the `winnow` feature swaps the hand-written citation tokenizer for an equivalent one
+//! built with the Winnow parser combinator library.
+//!
+//! The crate is `no_std` compatible. Disabling the default `std` feature and enabling `alloc`
+//! builds it without the standard library. Without `std` there is no clock to determine the
+//! current Congress, so Congresses after the current one are not rejected; any Congress up to
+//! 65535 is accepted.
+
+extern crate alloc;
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("capitol requires the `alloc` feature");
 
+mod amendment;
+mod chamber;
+mod cong_object_type;
+mod congress;
 mod constants;
 mod error;
+mod hyphenated;
+#[cfg(feature = "winnow")]
+mod legislation;
+mod scanner;
+mod statute;
 
-use std::fmt::Display;
-use std::str::FromStr;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::num::IntErrorKind;
+use core::ops::Range;
+use core::str::FromStr;
 
-use crate::constants::{BASE_URL, BILL_VERSIONS, CURRENT_CONGRESS};
-use crate::error::Error;
+pub use crate::amendment::Amendment;
+pub use crate::chamber::Chamber;
+pub use crate::cong_object_type::CongObjectType;
+pub use crate::congress::Congress;
+pub use crate::constants::BILL_VERSIONS_DESCRIPTIONS;
+use crate::constants::{
+    API_BASE_URL, BASE_URL, BILL_VERSIONS, BILL_VERSION_ALIASES, GOVINFO_BASE_URL,
+    GOVTRACK_BASE_URL, MAX_DOCUMENT_NUMBERS, ORDINAL_SUFFIXES, PROPUBLICA_API_BASE_URL,
+};
+pub use crate::error::Error;
+pub use crate::hyphenated::{NominationCitation, PublicLawCitation, TreatyCitation};
+pub use crate::scanner::CitationScanner;
+pub use crate::statute::StatuteCitation;
 
-type Result<T> = std::result::Result<T, Error>;
+type Result<T> = core::result::Result<T, Error>;
 
-#[derive(Debug, PartialEq)]
-struct Version(String);
+/// The version of a bill's text, e.g. `ih` for "Introduced in House".
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
+pub struct Version(String);
 
-#[derive(Debug, Default, PartialEq)]
-struct CiteBytes {
-    congress: Vec<u8>,
-    chamber: u8,
-    object_type: Vec<u8>,
-    number: Vec<u8>,
-    ver: Option<Vec<u8>>,
-}
+impl Version {
+    /// Create a version from its code, e.g. `"ih"`, case-insensitively.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Error, Version};
+    ///
+    /// let version = Version::new("IH").unwrap();
+    /// assert_eq!("ih", version.code());
+    /// assert_eq!("Introduced in House", version.description());
+    /// assert_eq!(Err(Error::InvalidBillVersion), Version::new("xx"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the code is not a recognized bill version.
+    pub fn new(code: &str) -> Result<Self> {
+        Self::parse(code.as_bytes())
+    }
 
-#[derive(Debug, PartialEq)]
-struct Congress(u64);
+    /// Get the version's code, e.g. `"ih"`.
+    pub fn code(&self) -> &str {
+        &self.0
+    }
 
-impl Congress {
     fn parse(input: &[u8]) -> Result<Self> {
-        match String::from_utf8(input.to_vec()) {
-            Ok(s) => {
-                let congress = s.parse::<u64>()?;
-                if congress <= *CURRENT_CONGRESS {
-                    Ok(Congress(congress))
-                } else {
-                    Err(Error::InvalidCongress)
-                }
-            }
-            Err(e) => Err(Error::FromUtf8(e)),
+        let input = input.to_ascii_lowercase();
+        if BILL_VERSIONS.binary_search(&input.as_slice()).is_ok() {
+            Ok(Version(String::from_utf8(input)?))
+        } else {
+            Err(Error::InvalidBillVersion)
         }
     }
 
-    fn as_ordinal(&self) -> String {
-        let mut ordinal = self.to_string();
-        if ordinal.ends_with('1') {
-            ordinal.push_str("st");
-        } else if ordinal.ends_with('2') {
-            ordinal.push_str("nd");
-        } else if ordinal.ends_with('3') {
-            ordinal.push_str("rd");
-        } else {
-            ordinal.push_str("th");
+    /// Parse the version of a document of the given type, which must be a bill or resolution,
+    /// from a version code or a long-form alias of one, such as `"introduced"`, which stands for
+    /// `ih` or `is` depending on the chamber.
+    fn parse_for(input: &[u8], object_type: &CongObjectType) -> Result<Self> {
+        if !object_type.is_measure() {
+            return Err(Error::InvalidBillVersion);
+        }
+        let chamber = object_type.chamber();
+        let lowercase = input.to_ascii_lowercase();
+        match BILL_VERSION_ALIASES
+            .iter()
+            .find(|(alias, _, _)| *alias == lowercase.as_slice())
+        {
+            Some((_, house, _)) if chamber == Chamber::House => Self::parse(house),
+            Some((_, _, senate)) => Self::parse(senate),
+            None => Self::parse(&lowercase),
         }
-        ordinal
+    }
+
+    /// Get the human-readable name of the version, e.g. "Introduced in House" for `ih`.
+    pub fn description(&self) -> &'static str {
+        version_description(&self.0).expect("versions are validated against BILL_VERSIONS")
+    }
+
+    /// Check whether the version is the text as introduced, `ih` or `is`.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Version;
+    ///
+    /// assert!("ih".parse::<Version>().unwrap().is_introduced());
+    /// assert!(!"eh".parse::<Version>().unwrap().is_introduced());
+    /// ```
+    pub fn is_introduced(&self) -> bool {
+        matches!(self.0.as_str(), "ih" | "is")
+    }
+
+    /// Check whether the version is the text as passed by the House, `eh` or `eah`.
+    pub fn is_passed_house(&self) -> bool {
+        matches!(self.0.as_str(), "eh" | "eah")
+    }
+
+    /// Check whether the version is the text as passed by the Senate, `es` or `eas`.
+    pub fn is_passed_senate(&self) -> bool {
+        matches!(self.0.as_str(), "es" | "eas")
+    }
+
+    /// Check whether the version is the enrolled text, `enr`, passed by both chambers and sent to
+    /// the President.
+    pub fn is_enrolled(&self) -> bool {
+        self.0 == "enr"
     }
 }
 
-impl Display for Congress {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+/// Parse a bill version code, e.g. `"ih"`, case-insensitively.
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
     }
 }
 
-#[derive(Debug, PartialEq)]
-enum Chamber {
-    House,
-    Senate,
+/// Validate a bill version code. See [`Version::new`].
+impl TryFrom<String> for Version {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::new(&value)
+    }
 }
 
-impl Display for Chamber {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::House => "house",
-                Self::Senate => "senate",
-            }
-        )
+/// Get the human-readable name of a bill version code.
+///
+/// Returns `None` if the code is not a recognized bill version.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::version_description;
+///
+/// assert_eq!(Some("Enrolled Bill"), version_description("enr"));
+/// assert_eq!(None, version_description("xx"));
+/// ```
+pub fn version_description(code: &str) -> Option<&'static str> {
+    BILL_VERSIONS_DESCRIPTIONS
+        .binary_search_by_key(&code, |(code, _)| code)
+        .ok()
+        .map(|index| BILL_VERSIONS_DESCRIPTIONS[index].1)
+}
+
+/// The raw parts of a citation, as split by [`Citation::tokenize`] before they are validated.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::Citation;
+///
+/// let parts = Citation::tokenize("118th-hr-815-ih");
+/// assert_eq!(b"118th", parts.congress.as_slice());
+/// assert_eq!(b'h', parts.chamber);
+/// assert_eq!(b"r", parts.object_type.as_slice());
+/// assert_eq!(b"815", parts.number.as_slice());
+/// assert_eq!(Some(b"ih".as_slice()), parts.ver.as_deref());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CiteBytes {
+    /// The Congress digits and any ordinal suffix, e.g. `118th`.
+    pub congress: Vec<u8>,
+    /// The chamber letter, `h` or `s` in either case, or 0 if the citation has none.
+    pub chamber: u8,
+    /// The object type letters following the chamber letter, e.g. `res` in `118hres1`.
+    pub object_type: Vec<u8>,
+    /// The document number digits.
+    pub number: Vec<u8>,
+    /// The letters following the number, if any, which should name a bill version.
+    pub ver: Option<Vec<u8>>,
+}
+
+/// The byte ranges of the input that the parts of a `CiteBytes` were read from.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct CiteSpans {
+    congress: Range<usize>,
+    object_type: Range<usize>,
+    number: Range<usize>,
+    ver: Range<usize>,
+}
+
+impl CiteSpans {
+    /// The offset just past the last part read, before any trailing input the tokenizer ignored.
+    fn end(&self) -> usize {
+        if self.ver.is_empty() {
+            self.number.end
+        } else {
+            self.ver.end
+        }
     }
 }
 
-impl Chamber {
-    fn parse(input: u8) -> Self {
-        if input.eq_ignore_ascii_case(&b'h') {
-            Self::House
+fn is_ordinal_suffix(input: &[u8]) -> bool {
+    ORDINAL_SUFFIXES.contains(&input.to_ascii_lowercase().as_slice())
+}
+
+/// Percent-encode everything in the input but the unreserved URL characters.
+pub(crate) fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len() * 3);
+    for byte in input.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
         } else {
-            Self::Senate
-        }
-    }
-}
-
-#[derive(Debug, PartialEq)]
-enum CongObjectType {
-    SenateBill,
-    HouseBill,
-    SenateResolution,
-    HouseResolution,
-    SenateConcurrentResolution,
-    HouseConcurrentResolution,
-    SenateJointResolution,
-    HouseJointResolution,
-    HouseReport,
-    SenateReport,
-}
-
-impl CongObjectType {
-    fn parse(input: &[u8], chamber: &Chamber) -> Result<Self> {
-        match input.to_ascii_lowercase().as_slice() {
-            b"" | b"r" if *chamber == Chamber::House => Ok(Self::HouseBill),
-            b"" if *chamber == Chamber::Senate => Ok(Self::SenateBill),
-            b"res" if *chamber == Chamber::House => Ok(Self::HouseResolution),
-            b"res" if *chamber == Chamber::Senate => Ok(Self::SenateResolution),
-            b"conres" if *chamber == Chamber::House => Ok(Self::HouseConcurrentResolution),
-            b"conres" if *chamber == Chamber::Senate => Ok(Self::SenateConcurrentResolution),
-            b"jres" if *chamber == Chamber::House => Ok(Self::HouseJointResolution),
-            b"jres" if *chamber == Chamber::Senate => Ok(Self::SenateJointResolution),
-            b"rpt" if *chamber == Chamber::House => Ok(Self::HouseReport),
-            b"rpt" if *chamber == Chamber::Senate => Ok(Self::SenateReport),
-            _ => Err(Error::UnknownCongObjectType),
+            encoded.push_str(&format!("%{byte:02X}"));
         }
     }
+    encoded
 }
 
-impl Display for CongObjectType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::HouseBill | Self::SenateBill => "bill",
-                Self::HouseResolution | Self::SenateResolution => "resolution",
-                Self::HouseConcurrentResolution | Self::SenateConcurrentResolution =>
-                    "concurrent-resolution",
-                Self::HouseJointResolution | Self::SenateJointResolution => "joint-resolution",
-                Self::HouseReport | Self::SenateReport => "report",
-            }
-        )
+/// Escape the characters with special meaning in HTML text and attribute values.
+fn html_escape(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Check that a document number is positive and fits the four bytes `Citation::to_bytes` gives
+/// it.
+fn is_valid_number(number: usize) -> bool {
+    number != 0 && u32::try_from(number).is_ok()
+}
+
+/// Parse a document number, which must be a positive integer.
+fn parse_number(input: &str) -> Result<usize> {
+    match input.parse::<usize>() {
+        Ok(number) if !is_valid_number(number) => Err(Error::InvalidBillNumber),
+        Ok(number) => Ok(number),
+        Err(e) if *e.kind() == IntErrorKind::Empty => Err(Error::InvalidBillNumber),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Parse a citation of the form `<PREFIX><CONGRESS>-<NUMBER>`, trying each prefix in turn.
+fn parse_hyphenated(input: &str, prefixes: &[&str]) -> Result<(Congress, usize)> {
+    let input = input.to_ascii_lowercase();
+    let rest = prefixes
+        .iter()
+        .find_map(|prefix| input.strip_prefix(prefix))
+        .ok_or(Error::UnknownCongObjectType)?;
+    let (congress, number) = rest.split_once('-').ok_or(Error::MissingHyphen)?;
+    let congress = Congress::parse(congress.as_bytes())?;
+    let number = parse_number(number)?;
+    Ok((congress, number))
+}
+
+/// A format in which GovInfo publishes the text of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextFormat {
+    /// HTML text.
+    Html,
+    /// XML text, in the format used by the legislative drafting offices.
+    Xml,
+    /// A PDF of the printed document.
+    Pdf,
+}
+
+impl TextFormat {
+    fn directory(&self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Xml => "xml",
+            Self::Pdf => "pdf",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Html => "htm",
+            Self::Xml => "xml",
+            Self::Pdf => "pdf",
+        }
+    }
+}
+
+/// A part of a document's record served by the Congress.gov API, for
+/// [`Citation::to_api_url_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiDetail {
+    /// The document itself.
+    Overview,
+    /// The actions taken on the document.
+    Actions,
+    /// The document's cosponsors.
+    Cosponsors,
+    /// The committees the document was referred to.
+    Committees,
+    /// The amendments to the document.
+    Amendments,
+    /// The versions of the document's text.
+    Text,
+}
+
+impl ApiDetail {
+    fn path(&self) -> &'static str {
+        match self {
+            Self::Overview => "",
+            Self::Actions => "/actions",
+            Self::Cosponsors => "/cosponsors",
+            Self::Committees => "/committees",
+            Self::Amendments => "/amendments",
+            Self::Text => "/text",
+        }
     }
 }
 
@@ -155,7 +386,13 @@ impl Display for CongObjectType {
 ///
 /// A `Citation` consists of a Congress, a Chamber, a Congressional object type, a number, and
 /// optionally for bills, a Version.
-#[derive(Debug, PartialEq)]
+///
+/// Citations are ordered by Congress, then by chamber (House before Senate), then by object type
+/// (bills, private bills, simple resolutions, concurrent resolutions, joint resolutions, reports,
+/// conference reports, executive reports, amendments, documents, and committee prints, in that
+/// order), then by number. Citations that differ only in version sort unversioned first, then
+/// alphabetically by version code.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Citation {
     congress: Congress,
     chamber: Chamber,
@@ -165,7 +402,36 @@ pub struct Citation {
 }
 
 impl Citation {
-    fn tokenize(input: &str) -> CiteBytes {
+    /// Split a citation into its raw parts without validating them, the first step of
+    /// [`Citation::parse`].
+    ///
+    /// Tokenizing never fails: parts that are missing are left empty, and any input after the
+    /// version is ignored. This is useful for finding out why a citation fails to parse.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let parts = Citation::tokenize("118xyz999");
+    /// assert_eq!(b"xyz", parts.object_type.as_slice());
+    /// assert!(Citation::parse("118xyz999").is_err());
+    /// ```
+    pub fn tokenize(input: &str) -> CiteBytes {
+        Self::split(input).0
+    }
+
+    /// Tokenize with the Winnow tokenizer if the `winnow` feature is enabled, and with the
+    /// hand-written tokenizer otherwise.
+    fn split(input: &str) -> (CiteBytes, CiteSpans) {
+        #[cfg(feature = "winnow")]
+        return legislation::tokenize_with_spans(input);
+        #[cfg(not(feature = "winnow"))]
+        return Self::tokenize_with_spans(input);
+    }
+
+    #[cfg_attr(all(feature = "winnow", not(test)), allow(dead_code))]
+    fn tokenize_with_spans(input: &str) -> (CiteBytes, CiteSpans) {
         let mut iter = input.as_bytes().iter().peekable();
 
         // initialize containers for various parts of the citation
@@ -176,12 +442,32 @@ impl Citation {
 
         // initialize parts container
         let mut parts = CiteBytes::default();
+        let mut spans = CiteSpans::default();
+        let position = |remaining: usize| input.len() - remaining;
 
-        while let Some(&ch) = iter.next_if(|&&ch| ch > b'0' && ch <= b'9') {
+        while let Some(&ch) = iter.next_if(|&&ch| ch.is_ascii_digit()) {
             congress_bytes.push(ch);
         }
 
+        // keep an ordinal suffix, as in "118th", for `Congress::parse` to strip
+        let mut lookahead = iter.clone();
+        if let (false, Some(&first), Some(&second)) = (
+            congress_bytes.is_empty(),
+            lookahead.next(),
+            lookahead.next(),
+        ) {
+            if is_ordinal_suffix(&[first, second]) {
+                congress_bytes.extend([first, second]);
+                iter = lookahead;
+            }
+        }
+
         parts.congress.clone_from(&congress_bytes);
+        spans.congress = 0..position(iter.len());
+
+        // hyphens may separate the parts of the citation, as in "118-HR-815"
+        iter.next_if_eq(&&b'-');
+        spans.object_type.start = position(iter.len());
 
         if let Some(&ch) = iter.next_if(|&&ch| ch == b'h' || ch == b'H' || ch == b's' || ch == b'S')
         {
@@ -193,30 +479,43 @@ impl Citation {
         }
 
         parts.object_type = type_bytes;
+        spans.object_type.end = position(iter.len());
+
+        iter.next_if_eq(&&b'-');
+        spans.number.start = position(iter.len());
 
         while let Some(&ch) = iter.next_if(|&&ch| ch.is_ascii_digit()) {
             number_bytes.push(ch);
         }
 
         parts.number = number_bytes;
+        spans.number.end = position(iter.len());
+
+        iter.next_if_eq(&&b'-');
+        spans.ver.start = position(iter.len());
 
         while let Some(&ch) = iter.next_if(|&&ch| ch.is_ascii_alphabetic()) {
             ver_bytes.push(ch);
         }
 
+        spans.ver.end = position(iter.len());
+
         if ver_bytes.is_empty() {
             parts.ver = None;
         } else {
             parts.ver = Some(ver_bytes);
         }
 
-        parts
+        (parts, spans)
     }
 
     /// Parse a legislative citation.
     ///
     /// The method first breaks up the citation into its constituent parts, then parses each of the
-    /// parts, validating that the given Congress does not exceed the current Congress.
+    /// parts, validating that the given Congress does not exceed the current Congress. The
+    /// Congress may be written as an ordinal, as in `118thhr815`, and the parts may be separated
+    /// by hyphens, as in `118-HR-815`. The version may be written as a long-form alias, as in
+    /// `118hr815introduced`, which is read as `ih`; see [`Citation::with_version`].
     ///
     /// Example
     ///
@@ -228,26 +527,35 @@ impl Citation {
     ///
     /// # Errors
     ///
-    /// Will result in an error if the Congress part of the citation is invalid (greater than the
-    /// current Congress), if the Congressional object type is unrecognized, if an integer can't be
-    /// parsed from the document number, or if the document is a bill and has an unrecognized
-    /// version type.
+    /// Will result in an error if the Congress part of the citation is invalid (zero, greater than
+    /// the current Congress, or written with the wrong ordinal suffix), if the Congressional object type is unrecognized, if the document number
+    /// is missing, zero, or too large, or if the citation has a version and is not a bill or
+    /// resolution or the version is unrecognized. Errors are wrapped in [`Error::ParseContext`] with the offending part of the
+    /// input and its byte offset.
     pub fn parse(input: &str) -> Result<Self> {
-        let bytes = Self::tokenize(input);
-        let congress = Congress::parse(&bytes.congress)?;
-        let chamber = Chamber::parse(bytes.chamber);
-        let object_type = CongObjectType::parse(&bytes.object_type, &chamber)?;
-        let number = String::from_utf8(bytes.number)?.parse::<usize>()?;
-        let ver = if let Some(v) = bytes.ver {
-            if BILL_VERSIONS.contains(&v.as_slice()) {
-                let text = String::from_utf8(v)?;
-                Some(Version(text))
-            } else {
-                return Err(Error::InvalidBillVersion);
+        let (bytes, spans) = Self::split(input);
+        let context = |span: Range<usize>| {
+            let fragment = input[span.clone()].to_string();
+            move |source| Error::ParseContext {
+                position: span.start,
+                fragment,
+                source: Box::new(source),
             }
-        } else {
-            None
         };
+        let congress = Congress::parse(&bytes.congress).map_err(context(spans.congress))?;
+        let chamber = Chamber::parse(bytes.chamber);
+        let object_type = CongObjectType::parse(&bytes.object_type, &chamber)
+            .map_err(context(spans.object_type))?;
+        let number = String::from_utf8(bytes.number)
+            .map_err(Error::from)
+            .and_then(|number| parse_number(&number))
+            .map_err(context(spans.number))?;
+        let ver = bytes
+            .ver
+            .as_deref()
+            .map(|ver| Version::parse_for(ver, &object_type))
+            .transpose()
+            .map_err(context(spans.ver))?;
 
         Ok(Citation {
             congress,
@@ -258,203 +566,3449 @@ impl Citation {
         })
     }
 
-    /// Get the citation's version.
+    /// Parse a Congress.gov URL, the inverse of [`Citation::to_url`].
     ///
-    /// Returns `None` if the citation has no version.
+    /// The `/bill/`, `/congressional-report/`, `/amendment/`, and `/congressional-document/`
+    /// collections are recognized. A trailing
+    /// `/text/<version>` segment sets the citation's version; other trailing segments such as
+    /// `/actions`, along with any query string or fragment, are ignored.
     ///
     /// Example
     ///
     /// ```rust
     /// use capitol::Citation;
     ///
-    /// let citation = Citation::parse("118hr815ih").unwrap();
-    /// assert_eq!(Some("ih"), citation.version());
-    ///
-    /// let citation = Citation::parse("118hr815").unwrap();
-    /// assert_eq!(None, citation.version());
+    /// let url = "https://www.congress.gov/bill/118th-congress/house-bill/815/text/ih";
+    /// let citation = Citation::from_url(url).unwrap();
+    /// assert_eq!(Citation::parse("118hr815ih").unwrap(), citation);
     /// ```
-    pub fn version(&self) -> Option<&str> {
-        if let Some(version) = &self.ver {
-            Some(&version.0)
-        } else {
-            None
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the URL is not a Congress.gov document URL, or if any of the
+    /// parts extracted from it fail the same validation as [`Citation::parse`].
+    pub fn from_url(url: &str) -> Result<Self> {
+        let path = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or(Error::InvalidUrl)?;
+        let path = path.strip_prefix("www.").unwrap_or(path);
+        let path = path
+            .strip_prefix("congress.gov/")
+            .ok_or(Error::InvalidUrl)?;
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+        let collection = segments.next().ok_or(Error::InvalidUrl)?;
+        let congress = segments
+            .next()
+            .and_then(|segment| segment.strip_suffix("-congress"))
+            .ok_or(Error::InvalidUrl)?;
+        let congress = Congress::parse(congress.as_bytes())?;
+        let object_type = match collection {
+            "executive-report" => CongObjectType::SenateExecutiveReport,
+            _ => CongObjectType::from_url_segment(segments.next().ok_or(Error::InvalidUrl)?)?,
+        };
+        if object_type.collection() != collection {
+            return Err(Error::InvalidUrl);
         }
+        let chamber = object_type.chamber();
+        let number = parse_number(segments.next().ok_or(Error::InvalidUrl)?)?;
+        let ver = match (segments.next(), segments.next()) {
+            (Some("text"), Some(ver)) => Some(Version::parse_for(ver.as_bytes(), &object_type)?),
+            _ => None,
+        };
+
+        Ok(Citation {
+            congress,
+            chamber,
+            object_type,
+            number,
+            ver,
+        })
     }
 
-    /// Converts a `Citation` to a URL on Congress.gov.
+    /// Create a citation from its typed parts, with the same validation as [`Citation::parse`].
     ///
     /// Example
     ///
     /// ```rust
-    /// use capitol::Citation;
+    /// use capitol::{Chamber, Citation, CongObjectType};
     ///
-    /// let url = "118hr815".parse::<Citation>().unwrap().to_url();
+    /// let citation =
+    ///     Citation::from_parts(118, Chamber::House, CongObjectType::HouseBill, 815).unwrap();
+    /// assert_eq!(Citation::parse("118hr815").unwrap(), citation);
     /// ```
-    pub fn to_url(&self) -> String {
-        let collection = match self.object_type {
-            CongObjectType::HouseReport | CongObjectType::SenateReport => "congressional-report",
-            _ => "bill",
-        };
-        let mut base = format!(
-            "{BASE_URL}/{collection}/{}-congress/{}-{}/{}",
-            self.congress.as_ordinal(),
-            self.chamber,
-            self.object_type,
-            self.number
-        );
-
-        if let Some(ver) = &self.ver {
-            base.push_str("/text/");
-            base.push_str(&ver.0);
-        }
-
-        base
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the Congress is greater than the current Congress, if the number
+    /// is zero, or if the chamber does not match the object type.
+    pub fn from_parts(
+        congress: u64,
+        chamber: Chamber,
+        object_type: CongObjectType,
+        number: usize,
+    ) -> Result<Self> {
+        Self::from_parts_with_version(congress, chamber, object_type, number, None)
     }
-}
 
-impl FromStr for Citation {
-    type Err = Error;
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Self::parse(s)
+    /// Create a citation from its typed parts and an optional bill version code.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Chamber, Citation, CongObjectType};
+    ///
+    /// let citation = Citation::from_parts_with_version(
+    ///     118,
+    ///     Chamber::House,
+    ///     CongObjectType::HouseBill,
+    ///     815,
+    ///     Some("ih"),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(Citation::parse("118hr815ih").unwrap(), citation);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error for the same reasons as [`Citation::from_parts`], or if a version
+    /// is given and the object type is not a bill or resolution or the version is unrecognized.
+    pub fn from_parts_with_version(
+        congress: u64,
+        chamber: Chamber,
+        object_type: CongObjectType,
+        number: usize,
+        ver: Option<&str>,
+    ) -> Result<Self> {
+        CitationBuilder::new()
+            .congress(congress)
+            .chamber(chamber)
+            .object_type(object_type)
+            .number(number)
+            .version(ver)
+            .build()
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
 
-    #[test]
-    fn test_tokenize_no_ver_house_bill() {
-        let mut input = "118hr8070";
-        let expected = CiteBytes {
-            congress: b"118".to_vec(),
-            chamber: b'h',
-            object_type: b"r".to_vec(),
-            number: b"8070".to_vec(),
-            ver: None,
-        };
-        let result = Citation::tokenize(&mut input);
-        assert_eq!(expected, result);
+    /// Check whether the citation is for a House or Senate bill.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118hr815").unwrap().is_bill());
+    /// assert!(!Citation::parse("118hres5").unwrap().is_bill());
+    /// ```
+    pub fn is_bill(&self) -> bool {
+        self.object_type.is_bill()
     }
 
-    #[test]
-    fn test_parse_no_ver_house_bill() {
-        let input = "118hr8070";
-        let expected = Citation {
-            congress: Congress(118),
-            chamber: Chamber::House,
-            object_type: CongObjectType::HouseBill,
-            number: 8070,
-            ver: None,
-        };
-        let result = input.parse();
-        assert_eq!(expected, result.unwrap());
+    /// Check whether the citation is for a simple, concurrent, or joint resolution.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118sjres1").unwrap().is_resolution());
+    /// assert!(!Citation::parse("118s1").unwrap().is_resolution());
+    /// ```
+    pub fn is_resolution(&self) -> bool {
+        self.object_type.is_resolution()
     }
 
-    #[test]
-    fn test_parse_house_bill() {
-        let input = "118hrpt529";
-        let expected = Citation {
-            congress: Congress(118),
-            chamber: Chamber::House,
-            object_type: CongObjectType::HouseReport,
-            number: 529,
-            ver: None,
-        };
-        let result = input.parse();
-        assert_eq!(expected, result.unwrap());
+    /// Check whether the citation is for a committee report.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118hrpt529").unwrap().is_report());
+    /// ```
+    pub fn is_report(&self) -> bool {
+        self.object_type.is_report()
     }
 
-    #[test]
-    fn test_parse_senate_bill() {
-        let input = "118srpt17";
-        let expected = Citation {
-            congress: Congress(118),
-            chamber: Chamber::Senate,
-            object_type: CongObjectType::SenateReport,
-            number: 17,
-            ver: None,
-        };
-        let result = input.parse();
-        assert_eq!(expected, result.unwrap());
+    /// Check whether the citation is for a measure, that is, a bill or resolution.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118hconres3").unwrap().is_measure());
+    /// assert!(!Citation::parse("118hdoc1").unwrap().is_measure());
+    /// ```
+    pub fn is_measure(&self) -> bool {
+        self.object_type.is_measure()
     }
 
-    #[test]
-    fn test_tokenize_no_ver_senate_bill() {
+    /// Get the citation's Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!("118", citation.congress().to_string());
+    /// ```
+    pub fn congress(&self) -> &Congress {
+        &self.congress
+    }
+
+    /// Get the citation's Congress number.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(118, citation.congress_number());
+    /// ```
+    pub fn congress_number(&self) -> u64 {
+        self.congress.0
+    }
+
+    /// Get the calendar year in which the citation's Congress began, as with
+    /// [`Congress::start_year`].
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert_eq!(2023, Citation::parse("118hr815").unwrap().year());
+    /// assert_eq!(1789, Citation::parse("1s1").unwrap().year());
+    /// ```
+    pub fn year(&self) -> u64 {
+        self.congress.start_year()
+    }
+
+    /// Get the citation's chamber.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Chamber, Citation};
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(&Chamber::House, citation.chamber());
+    /// ```
+    pub fn chamber(&self) -> &Chamber {
+        &self.chamber
+    }
+
+    /// Get the citation's Congressional object type.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Citation, CongObjectType};
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(&CongObjectType::HouseBill, citation.object_type());
+    /// ```
+    pub fn object_type(&self) -> &CongObjectType {
+        &self.object_type
+    }
+
+    /// Get the citation's document number.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(815, citation.number());
+    /// ```
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Get the citation's version.
+    ///
+    /// Returns `None` if the citation has no version.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815ih").unwrap();
+    /// assert_eq!(Some("ih"), citation.version());
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(None, citation.version());
+    /// ```
+    pub fn version(&self) -> Option<&str> {
+        if let Some(version) = &self.ver {
+            Some(&version.0)
+        } else {
+            None
+        }
+    }
+
+    /// Get the lowercase chamber letter used in the compact citation form.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!("h", citation.chamber_abbreviation());
+    ///
+    /// let citation = Citation::parse("118S5").unwrap();
+    /// assert_eq!("s", citation.chamber_abbreviation());
+    /// ```
+    pub fn chamber_abbreviation(&self) -> &'static str {
+        self.chamber.code()
+    }
+
+    /// Check whether the citation is for the enrolled (`enr`) version of a bill, the text passed
+    /// by both chambers and sent to the President.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118hr815enr").unwrap().is_enacted());
+    /// assert!(!Citation::parse("118hr815").unwrap().is_enacted());
+    /// ```
+    pub fn is_enacted(&self) -> bool {
+        self.ver.as_ref().is_some_and(Version::is_enrolled)
+    }
+
+    /// Check whether the citation is for the introduced version of a bill, `ih` or `is`.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118s5is").unwrap().is_introduced());
+    /// assert!(!Citation::parse("118s5es").unwrap().is_introduced());
+    /// ```
+    pub fn is_introduced(&self) -> bool {
+        self.ver.as_ref().is_some_and(Version::is_introduced)
+    }
+
+    /// Create a copy of the citation with a different bill version.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815ih").unwrap();
+    /// assert_eq!(Some("enr"), citation.with_version("enr").unwrap().version());
+    /// ```
+    ///
+    /// Besides the version codes, the aliases "enacted" and "enrolled" (`enr`), "engrossed"
+    /// (`eh` or `es`), "introduced" (`ih` or `is`), and "reported" (`rh` or `rs`) are accepted,
+    /// choosing the code for the citation's chamber.
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the citation is not a bill or resolution, or if the version is
+    /// not a recognized bill version or alias.
+    pub fn with_version(&self, ver: &str) -> Result<Self> {
+        Ok(Citation {
+            ver: Some(Version::parse_for(ver.as_bytes(), &self.object_type)?),
+            ..self.clone()
+        })
+    }
+
+    /// List the version codes that can apply to the citation's document, for use with
+    /// [`Citation::with_version`].
+    ///
+    /// These are the versions that name the citation's chamber, such as "Introduced in House",
+    /// and the versions that name neither chamber, such as "Public Print". Only resolutions are
+    /// "Agreed to", and simple resolutions are never enrolled. Documents other than bills and
+    /// resolutions have no versions.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let versions = Citation::parse("118hr815").unwrap().related_versions();
+    /// assert!(versions.contains(&"ih"));
+    /// assert!(versions.contains(&"enr"));
+    /// assert!(!versions.contains(&"is"));
+    /// assert!(Citation::parse("118hrpt529").unwrap().related_versions().is_empty());
+    /// ```
+    pub fn related_versions(&self) -> Vec<&'static str> {
+        if !self.is_measure() {
+            return Vec::new();
+        }
+        let (chamber, other) = match self.chamber {
+            Chamber::House => ("House", "Senate"),
+            Chamber::Senate => ("Senate", "House"),
+        };
+        BILL_VERSIONS_DESCRIPTIONS
+            .iter()
+            .filter(|(_, description)| {
+                description.contains(chamber) || !description.contains(other)
+            })
+            .filter(|(code, _)| match *code {
+                "ath" | "ats" => self.object_type.is_resolution(),
+                "enr" => !matches!(
+                    self.object_type,
+                    CongObjectType::HouseResolution | CongObjectType::SenateResolution
+                ),
+                _ => true,
+            })
+            .map(|(code, _)| *code)
+            .collect()
+    }
+
+    /// Create a copy of the citation without a bill version.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815ih").unwrap();
+    /// assert_eq!(Citation::parse("118hr815").unwrap(), citation.without_version());
+    /// ```
+    pub fn without_version(&self) -> Self {
+        Citation {
+            ver: None,
+            ..self.clone()
+        }
+    }
+
+    /// Converts a `Citation` to its canonical compact citation string.
+    ///
+    /// The string is lowercase with no separators, e.g. `"118hr815ih"`, and always parses back to
+    /// an equal `Citation`, which makes it suitable for storage. It matches the `Display` form.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118th-HR-815-IH").unwrap();
+    /// assert_eq!("118hr815ih", citation.to_citation_string());
+    /// assert_eq!(citation, Citation::parse(&citation.to_citation_string()).unwrap());
+    /// ```
+    pub fn to_citation_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Converts a `Citation` to its shortest citation string, omitting the Congress when it is
+    /// the current Congress, e.g. `"hr815"` rather than `"119hr815"`.
+    ///
+    /// This is lossy: the short form of a current-Congress citation no longer says which Congress
+    /// it belongs to, and reads as a different Congress once the current one ends. Use
+    /// `Citation::from_short_citation` with the intended Congress to read it back, and
+    /// `to_citation_string` for anything stored. Requires the `std` feature, which provides the
+    /// clock used to find the current Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("117hr815ih").unwrap();
+    /// assert_eq!("117hr815ih", citation.to_short_citation());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_short_citation(&self) -> String {
+        let citation = self.to_string();
+        if self.congress.is_current() {
+            citation[self.congress.to_string().len()..].to_string()
+        } else {
+            citation
+        }
+    }
+
+    /// Parse a citation that may omit its Congress, as produced by `to_short_citation`, using
+    /// `default_congress` when none is given.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert_eq!(
+    ///     Citation::parse("118hr815").unwrap(),
+    ///     Citation::from_short_citation("hr815", 118).unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in the same errors as [`parse_with_default_congress`].
+    pub fn from_short_citation(input: &str, default_congress: u64) -> Result<Citation> {
+        parse_with_default_congress(input, default_congress)
+    }
+
+    /// Encode the citation in a compact, fixed binary layout of twelve bytes.
+    ///
+    /// | Bytes | Content                                                                   |
+    /// |-------|---------------------------------------------------------------------------|
+    /// | 0–1   | Congress, as a big-endian `u16`                                           |
+    /// | 2     | Chamber: 0 for the House, 1 for the Senate                                |
+    /// | 3     | Object type tag, listed below                                             |
+    /// | 4–7   | Document number, as a big-endian `u32`                                    |
+    /// | 8–11  | Version code in lowercase ASCII, padded with zero bytes; all zero if none |
+    ///
+    /// The object type tags are 0 for `SenateBill`, 1 `HouseBill`, 2 `SenatePrivateBill`,
+    /// 3 `HousePrivateBill`, 4 `SenateResolution`, 5 `HouseResolution`,
+    /// 6 `SenateConcurrentResolution`, 7 `HouseConcurrentResolution`, 8 `SenateJointResolution`,
+    /// 9 `HouseJointResolution`, 10 `HouseReport`, 11 `SenateReport`, 12 `HouseConferenceReport`,
+    /// 13 `SenateConferenceReport`, 14 `SenateExecutiveReport`, 15 `HouseAmendment`,
+    /// 16 `SenateAmendment`, 17 `HouseDocument`, 18 `SenateDocument`, 19 `HousePrint`, and
+    /// 20 `SenatePrint`. Tags are never reassigned; new object types take new tags.
+    ///
+    /// Every citation fits the layout, since document numbers are limited to four bytes and
+    /// Congresses to two. The encoding is decoded with `Citation::try_from(&[u8])`.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815ih").unwrap();
+    /// let bytes = citation.to_bytes();
+    /// assert_eq!(vec![0, 118, 0, 1, 0, 0, 3, 47, b'i', b'h', 0, 0], bytes);
+    /// assert_eq!(citation, Citation::try_from(bytes.as_slice()).unwrap());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let congress = u16::try_from(self.congress.0).expect("Congresses fit in two bytes");
+        let number = u32::try_from(self.number).expect("document numbers fit in four bytes");
+        let mut ver = [0; 4];
+        if let Some(ver_code) = &self.ver {
+            ver[..ver_code.0.len()].copy_from_slice(ver_code.0.as_bytes());
+        }
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend(congress.to_be_bytes());
+        bytes.push(match self.chamber {
+            Chamber::House => 0,
+            Chamber::Senate => 1,
+        });
+        bytes.push(self.object_type.binary_tag());
+        bytes.extend(number.to_be_bytes());
+        bytes.extend(ver);
+        bytes
+    }
+
+    /// Describe the citation in plain English, e.g. "House Bill 815 (118th Congress)".
+    ///
+    /// The version, if any, is described by name after the Congress.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert_eq!(
+    ///     "House Bill 815 (118th Congress)",
+    ///     Citation::parse("118hr815").unwrap().fmt_long()
+    /// );
+    /// assert_eq!(
+    ///     "Senate Resolution 17 (118th Congress, Introduced in Senate)",
+    ///     Citation::parse("118sres17is").unwrap().fmt_long()
+    /// );
+    /// ```
+    pub fn fmt_long(&self) -> String {
+        let mut long = format!(
+            "{} {} ({} Congress",
+            self.object_type.full_name(),
+            self.number,
+            self.congress.as_ordinal()
+        );
+        if let Some(ver) = &self.ver {
+            long.push_str(", ");
+            long.push_str(ver.description());
+        }
+        long.push(')');
+        long
+    }
+
+    /// Render the citation as an HTML link to its page on Congress.gov.
+    ///
+    /// The anchor text is `text` if given, and the [`Citation::fmt_long`] description otherwise.
+    /// The text is HTML-escaped, so it is safe to pass user input.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     r#"<a href="https://www.congress.gov/bill/118th-congress/house-bill/815">H.R. 815</a>"#,
+    ///     citation.to_html_link(Some("H.R. 815"))
+    /// );
+    /// ```
+    pub fn to_html_link(&self, text: Option<&str>) -> String {
+        let text = match text {
+            Some(text) => html_escape(text),
+            None => html_escape(&self.fmt_long()),
+        };
+        format!("<a href=\"{}\">{text}</a>", html_escape(&self.to_url()))
+    }
+
+    /// Converts a `Citation` to a URL on Congress.gov.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = "118hr815".parse::<Citation>().unwrap().to_url();
+    /// ```
+    pub fn to_url(&self) -> String {
+        let mut base = self.landing_url();
+
+        if let Some(ver) = &self.ver {
+            base.push_str("/text/");
+            base.push_str(&ver.0);
+        }
+
+        base
+    }
+
+    /// Converts a `Citation` to the URL of its summary page on Congress.gov.
+    ///
+    /// Returns `None` for committee reports, amendments, documents, and committee prints, which
+    /// have no summary page.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     Some("https://www.congress.gov/bill/118th-congress/house-bill/815/summary".to_string()),
+    ///     citation.to_bill_summary_url()
+    /// );
+    /// ```
+    pub fn to_bill_summary_url(&self) -> Option<String> {
+        self.is_measure().then(|| self.tab_url("summary"))
+    }
+
+    /// Converts a `Citation` to the URL of the Congressional Record, browsed by date, for the first
+    /// year of its Congress.
+    ///
+    /// Individual measures have no calendar page, so this is where to start looking for when a
+    /// measure was scheduled for floor debate. Returns `None` for anything other than a bill or
+    /// resolution.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     Some("https://www.congress.gov/congressional-record/browse-by-date/2023".to_string()),
+    ///     citation.to_congress_calendar_url()
+    /// );
+    /// assert_eq!(None, Citation::parse("118hrpt529").unwrap().to_congress_calendar_url());
+    /// ```
+    pub fn to_congress_calendar_url(&self) -> Option<String> {
+        self.is_measure().then(|| {
+            format!(
+                "{BASE_URL}/congressional-record/browse-by-date/{}",
+                self.year()
+            )
+        })
+    }
+
+    /// Converts a `Citation` to a Congress.gov search URL for documents from the same Congress
+    /// and chamber.
+    ///
+    /// The search is encoded as Congress.gov's JSON `q` query parameter.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     "https://www.congress.gov/search?q=%7B%22congress%22%3A%22118%22%2C%22chamber%22%3A%22House%22%7D",
+    ///     citation.search_url()
+    /// );
+    /// ```
+    pub fn search_url(&self) -> String {
+        let query = format!(
+            r#"{{"congress":"{}","chamber":"{}"}}"#,
+            self.congress,
+            self.chamber.name()
+        );
+        format!("{BASE_URL}/search?q={}", percent_encode(&query))
+    }
+
+    /// Converts a `Citation` to the URL of its "All Info" page on Congress.gov.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     "https://www.congress.gov/bill/118th-congress/house-bill/815/all-info",
+    ///     citation.to_all_info_url()
+    /// );
+    /// ```
+    pub fn to_all_info_url(&self) -> String {
+        self.tab_url("all-info")
+    }
+
+    /// Converts a `Citation` to the URL of its actions page on Congress.gov.
+    ///
+    /// The tab URLs, this and [`Citation::cosponsors_url`], [`Citation::committees_url`],
+    /// [`Citation::text_url`], and [`Citation::amendments_url`], are built for every object type,
+    /// but not every document has every tab. Bills and resolutions have all of them; reports
+    /// have only text, and amendments, documents, and committee prints have no cosponsors or
+    /// committees. The missing pages are not found on Congress.gov.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     "https://www.congress.gov/bill/118th-congress/house-bill/815/actions",
+    ///     citation.actions_url()
+    /// );
+    /// ```
+    pub fn actions_url(&self) -> String {
+        self.tab_url("actions")
+    }
+
+    /// Converts a `Citation` to the URL of its cosponsors page on Congress.gov.
+    ///
+    /// See [`Citation::actions_url`] for which documents have this page.
+    pub fn cosponsors_url(&self) -> String {
+        self.tab_url("cosponsors")
+    }
+
+    /// Converts a `Citation` to the URL of its committees page on Congress.gov.
+    ///
+    /// See [`Citation::actions_url`] for which documents have this page.
+    pub fn committees_url(&self) -> String {
+        self.tab_url("committees")
+    }
+
+    /// Converts a `Citation` to the URL of its text page on Congress.gov.
+    ///
+    /// Unlike [`Citation::to_url`], this ignores the citation's version, so Congress.gov shows
+    /// its latest text. For a particular version, see [`Citation::text_url_for_version`].
+    pub fn text_url(&self) -> String {
+        self.tab_url("text")
+    }
+
+    /// Converts a `Citation` to the URL of the given version of its text on Congress.gov.
+    ///
+    /// The version is a code or alias, as accepted by [`Citation::with_version`], and replaces
+    /// any version the citation carries.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815").unwrap();
+    /// assert_eq!(
+    ///     "https://www.congress.gov/bill/118th-congress/house-bill/815/text/eh",
+    ///     citation.text_url_for_version("engrossed").unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the version is not a recognized bill version or alias.
+    pub fn text_url_for_version(&self, ver: &str) -> Result<String> {
+        Ok(self.with_version(ver)?.to_url())
+    }
+
+    /// Converts a `Citation` to the URL of its amendments page on Congress.gov.
+    ///
+    /// See [`Citation::actions_url`] for which documents have this page.
+    pub fn amendments_url(&self) -> String {
+        self.tab_url("amendments")
+    }
+
+    /// Converts a `Citation` to the URL of its enrolled text on Congress.gov.
+    ///
+    /// The URL always points to the `enr` version, whatever version the citation itself carries.
+    /// Returns `None` for anything other than a bill.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let citation = Citation::parse("118hr815ih").unwrap();
+    /// assert_eq!(
+    ///     Some("https://www.congress.gov/bill/118th-congress/house-bill/815/text/enr".to_string()),
+    ///     citation.to_enrolled_bill_url()
+    /// );
+    /// ```
+    pub fn to_enrolled_bill_url(&self) -> Option<String> {
+        self.is_bill()
+            .then(|| format!("{}/text/enr", self.landing_url()))
+    }
+
+    /// Converts a `Citation` to the URL of its HTML text on GovInfo.gov.
+    ///
+    /// GovInfo packages bills and resolutions by text version, so those citations must carry a
+    /// version. Committee reports and documents are packaged without one.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = Citation::parse("118hr815ih").unwrap().to_govinfo_url().unwrap();
+    /// assert_eq!(
+    ///     "https://www.govinfo.gov/content/pkg/BILLS-118hr815ih/html/BILLS-118hr815ih.htm",
+    ///     url
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the citation is a bill or resolution without a version, or if
+    /// it is an amendment, which GovInfo does not package separately.
+    pub fn to_govinfo_url(&self) -> Result<String> {
+        self.to_text_url(TextFormat::Html)
+    }
+
+    /// Converts a committee report `Citation` to the URL of its HTML text on GovInfo.gov.
+    ///
+    /// The package id is `CRPT-{congress}{chamber}rpt{number}`. Conference reports are numbered
+    /// as committee reports, so they are packaged the same way.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = Citation::parse("118hrpt529").unwrap().to_govinfo_report_url().unwrap();
+    /// assert_eq!(
+    ///     "https://www.govinfo.gov/content/pkg/CRPT-118hrpt529/html/CRPT-118hrpt529.htm",
+    ///     url
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the citation is not a House or Senate committee or conference
+    /// report. Senate executive reports are not numbered by chamber; see
+    /// [`Citation::to_govinfo_url`].
+    pub fn to_govinfo_report_url(&self) -> Result<String> {
+        match self.object_type {
+            CongObjectType::HouseReport
+            | CongObjectType::SenateReport
+            | CongObjectType::HouseConferenceReport
+            | CongObjectType::SenateConferenceReport => {
+                let package = format!(
+                    "CRPT-{}{}rpt{}",
+                    self.congress,
+                    self.chamber.code(),
+                    self.number
+                );
+                Ok(format!(
+                    "{GOVINFO_BASE_URL}/content/pkg/{package}/html/{package}.htm"
+                ))
+            }
+            _ => Err(Error::UnknownCongObjectType),
+        }
+    }
+
+    /// Converts a `Citation` to the URL of its text on GovInfo.gov in the given format.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Citation, TextFormat};
+    ///
+    /// let url = Citation::parse("118hr815ih").unwrap().to_text_url(TextFormat::Pdf).unwrap();
+    /// assert_eq!(
+    ///     "https://www.govinfo.gov/content/pkg/BILLS-118hr815ih/pdf/BILLS-118hr815ih.pdf",
+    ///     url
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error for the same reasons as [`Citation::to_govinfo_url`].
+    pub fn to_text_url(&self, format: TextFormat) -> Result<String> {
+        let collection = self
+            .object_type
+            .govinfo_docclass()
+            .ok_or(Error::UnknownCongObjectType)?;
+        // only bills and resolutions are packaged by version
+        let ver = match (collection, self.version()) {
+            ("BILLS", None) => return Err(Error::MissingBillVersion),
+            ("BILLS", Some(ver)) => ver,
+            _ => "",
+        };
+        let package = format!(
+            "{collection}-{}{}{}{ver}",
+            self.congress,
+            self.object_type.code(),
+            self.number
+        );
+        Ok(format!(
+            "{GOVINFO_BASE_URL}/content/pkg/{package}/{}/{package}.{}",
+            format.directory(),
+            format.extension()
+        ))
+    }
+
+    /// Converts a `Citation` to its Congress.gov API (v3) endpoint URL.
+    ///
+    /// The endpoint returns data about the document rather than a web page, so the citation's
+    /// version is not part of the URL. Requests to the API also need a key; see
+    /// [`Citation::to_api_url_with_key`].
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = Citation::parse("118hr815").unwrap().to_api_url().unwrap();
+    /// assert_eq!("https://api.congress.gov/v3/bill/118/hr/815", url);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the API has no endpoint for the citation's object type, as is
+    /// the case for House and Senate documents and Senate executive reports.
+    pub fn to_api_url(&self) -> Result<String> {
+        let collection = self
+            .object_type
+            .api_collection()
+            .ok_or(Error::UnknownCongObjectType)?;
+        Ok(format!(
+            "{API_BASE_URL}/{collection}/{}/{}/{}",
+            self.congress,
+            self.object_type.code(),
+            self.number
+        ))
+    }
+
+    /// Converts a `Citation` to its ProPublica Congress API endpoint URL.
+    ///
+    /// ProPublica expects its API key in an `X-API-Key` request header rather than in the URL.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = Citation::parse("118hr815").unwrap().to_propublica_url().unwrap();
+    /// assert_eq!(
+    ///     "https://api.propublica.org/congress/v1/118/house/bills/hr815.json",
+    ///     url
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the citation is not a bill or resolution, the only documents
+    /// the API serves by citation.
+    pub fn to_propublica_url(&self) -> Result<String> {
+        if !self.is_measure() {
+            return Err(Error::UnknownCongObjectType);
+        }
+        Ok(format!(
+            "{PROPUBLICA_API_BASE_URL}/{}/{}/bills/{}{}.json",
+            self.congress,
+            self.chamber,
+            self.object_type.code(),
+            self.number
+        ))
+    }
+
+    /// Converts a `Citation` to the URL of its page on GovTrack.us.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = Citation::parse("118hr815").unwrap().to_govtrack_url().unwrap();
+    /// assert_eq!("https://www.govtrack.us/congress/bills/118/hr815", url);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the citation is not a bill or resolution, the only documents
+    /// GovTrack.us tracks by citation.
+    pub fn to_govtrack_url(&self) -> Result<String> {
+        if !self.is_measure() {
+            return Err(Error::UnknownCongObjectType);
+        }
+        Ok(format!(
+            "{GOVTRACK_BASE_URL}/congress/bills/{}/{}{}",
+            self.congress,
+            self.object_type.code(),
+            self.number
+        ))
+    }
+
+    /// Converts a `Citation` to its Congress.gov API (v3) endpoint URL, with an API key.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// let url = Citation::parse("118hrpt529").unwrap().to_api_url_with_key("DEMO_KEY").unwrap();
+    /// assert_eq!(
+    ///     "https://api.congress.gov/v3/committee-report/118/hrpt/529?api_key=DEMO_KEY",
+    ///     url
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the API has no endpoint for the citation's object type.
+    pub fn to_api_url_with_key(&self, key: &str) -> Result<String> {
+        Ok(format!("{}?api_key={key}", self.to_api_url()?))
+    }
+
+    /// Converts a `Citation` to the Congress.gov API (v3) endpoint URL for part of its record.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{ApiDetail, Citation};
+    ///
+    /// let url = Citation::parse("118hr815").unwrap().to_api_url_for(ApiDetail::Actions).unwrap();
+    /// assert_eq!("https://api.congress.gov/v3/bill/118/hr/815/actions", url);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the API has no endpoint for the citation's object type, or
+    /// none for the detail: committee reports have only their text, and amendments have no
+    /// committees.
+    pub fn to_api_url_for(&self, detail: ApiDetail) -> Result<String> {
+        let supported = match detail {
+            ApiDetail::Overview | ApiDetail::Text => true,
+            ApiDetail::Committees => self.is_measure(),
+            ApiDetail::Actions | ApiDetail::Cosponsors | ApiDetail::Amendments => !self.is_report(),
+        };
+        if !supported {
+            return Err(Error::UnknownCongObjectType);
+        }
+        Ok(format!("{}{}", self.to_api_url()?, detail.path()))
+    }
+
+    /// The URL of one of the tabs on the document's Congress.gov page, such as "actions".
+    fn tab_url(&self, tab: &str) -> String {
+        format!("{}/{tab}", self.landing_url())
+    }
+
+    fn landing_url(&self) -> String {
+        let collection = self.object_type.collection();
+        let congress = self.congress.as_ordinal();
+        if self.object_type.is_cited_by_chamber() {
+            format!(
+                "{BASE_URL}/{collection}/{congress}-congress/{}-{}/{}",
+                self.chamber, self.object_type, self.number
+            )
+        } else {
+            format!(
+                "{BASE_URL}/{collection}/{congress}-congress/{}",
+                self.number
+            )
+        }
+    }
+
+    /// Get the approximate highest document number of the given type issued in a Congress.
+    ///
+    /// The estimate comes from a table of historical data covering the 110th through 118th
+    /// Congresses. Returns `None` for Congresses not yet in the table.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::{Citation, CongObjectType};
+    ///
+    /// let max = Citation::estimated_max_bill_number(118, &CongObjectType::HouseBill);
+    /// assert!(max.is_some_and(|max| max > 10_000));
+    ///
+    /// assert_eq!(None, Citation::estimated_max_bill_number(90, &CongObjectType::HouseBill));
+    /// ```
+    pub fn estimated_max_bill_number(congress: u64, object_type: &CongObjectType) -> Option<u32> {
+        let (_, maxima) = MAX_DOCUMENT_NUMBERS
+            .iter()
+            .find(|(number, _)| *number == congress)?;
+        let column = match object_type {
+            // private bills are numbered alongside public bills
+            CongObjectType::SenateBill | CongObjectType::SenatePrivateBill => 0,
+            CongObjectType::HouseBill | CongObjectType::HousePrivateBill => 1,
+            CongObjectType::SenateResolution => 2,
+            CongObjectType::HouseResolution => 3,
+            CongObjectType::SenateConcurrentResolution => 4,
+            CongObjectType::HouseConcurrentResolution => 5,
+            CongObjectType::SenateJointResolution => 6,
+            CongObjectType::HouseJointResolution => 7,
+            CongObjectType::HouseReport | CongObjectType::HouseConferenceReport => 8,
+            CongObjectType::SenateReport | CongObjectType::SenateConferenceReport => 9,
+            CongObjectType::SenateExecutiveReport
+            | CongObjectType::HouseAmendment
+            | CongObjectType::SenateAmendment
+            | CongObjectType::HouseDocument
+            | CongObjectType::SenateDocument
+            | CongObjectType::HousePrint
+            | CongObjectType::SenatePrint => return None,
+        };
+        Some(maxima[column])
+    }
+
+    /// Check whether the citation's number falls within the range historically issued for its
+    /// Congress and document type.
+    ///
+    /// Citations from Congresses without historical data are assumed to be plausible.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::Citation;
+    ///
+    /// assert!(Citation::parse("118hr815").unwrap().has_plausible_number_for_congress());
+    /// assert!(!Citation::parse("118hr99999").unwrap().has_plausible_number_for_congress());
+    /// ```
+    pub fn has_plausible_number_for_congress(&self) -> bool {
+        Self::estimated_max_bill_number(self.congress.0, &self.object_type)
+            .is_none_or(|max| self.number <= max as usize)
+    }
+}
+
+impl FromStr for Citation {
+    type Err = Error;
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Compare a citation with its canonical string form, ignoring case.
+///
+/// Other spellings that parse to the same citation, such as `"118-hr-815"`, are not equal.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::Citation;
+///
+/// let citation = Citation::parse("118hr815").unwrap();
+/// assert!(citation == "118hr815");
+/// assert!("118HR815" == citation);
+/// assert!(citation != "118s5");
+/// ```
+impl PartialEq<str> for Citation {
+    fn eq(&self, other: &str) -> bool {
+        self.to_string().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for Citation {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<Citation> for str {
+    fn eq(&self, other: &Citation) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<Citation> for &str {
+    fn eq(&self, other: &Citation) -> bool {
+        *other == **self
+    }
+}
+
+impl Display for Citation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let chamber = if self.object_type.is_cited_by_chamber() {
+            self.chamber.code()
+        } else {
+            ""
+        };
+        write!(
+            f,
+            "{}{chamber}{}{}",
+            self.congress,
+            self.object_type.abbreviation(),
+            self.number
+        )?;
+        if let Some(ver) = &self.ver {
+            f.write_str(&ver.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Assembles a [`Citation`] from its parts, with the same validation as [`Citation::parse`].
+///
+/// The chamber may be omitted, since every object type belongs to a chamber; if given, it must
+/// agree with the object type.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{Chamber, Citation, CitationBuilder, CongObjectType};
+///
+/// let citation = CitationBuilder::new()
+///     .congress(118)
+///     .chamber(Chamber::House)
+///     .object_type(CongObjectType::HouseBill)
+///     .number(815)
+///     .version(Some("ih"))
+///     .build()
+///     .unwrap();
+/// assert_eq!(Citation::parse("118hr815ih").unwrap(), citation);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CitationBuilder<'a> {
+    congress: Option<u64>,
+    chamber: Option<Chamber>,
+    object_type: Option<CongObjectType>,
+    number: Option<usize>,
+    ver: Option<&'a str>,
+}
+
+impl<'a> CitationBuilder<'a> {
+    /// Create a builder with no parts set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the Congress number.
+    pub fn congress(mut self, congress: u64) -> Self {
+        self.congress = Some(congress);
+        self
+    }
+
+    /// Set the chamber.
+    pub fn chamber(mut self, chamber: Chamber) -> Self {
+        self.chamber = Some(chamber);
+        self
+    }
+
+    /// Set the Congressional object type.
+    pub fn object_type(mut self, object_type: CongObjectType) -> Self {
+        self.object_type = Some(object_type);
+        self
+    }
+
+    /// Set the document number.
+    pub fn number(mut self, number: usize) -> Self {
+        self.number = Some(number);
+        self
+    }
+
+    /// Set or clear the bill version code.
+    pub fn version(mut self, ver: Option<&'a str>) -> Self {
+        self.ver = ver;
+        self
+    }
+
+    /// Build the `Citation`.
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the Congress, object type, or number is missing, if the Congress
+    /// is greater than the current Congress, if the number is zero, if the chamber does not match
+    /// the object type, or if a version is set and the object type is not a bill or resolution or
+    /// the version is unrecognized.
+    pub fn build(self) -> Result<Citation> {
+        let congress = Congress::new(self.congress.ok_or(Error::MissingField("congress"))?)?;
+        let object_type = self.object_type.ok_or(Error::MissingField("object type"))?;
+        let chamber = object_type.chamber();
+        if self.chamber.is_some_and(|given| given != chamber) {
+            return Err(Error::ChamberMismatch);
+        }
+        let number = self.number.ok_or(Error::MissingField("number"))?;
+        if !is_valid_number(number) {
+            return Err(Error::InvalidBillNumber);
+        }
+        let ver = self
+            .ver
+            .map(|ver| Version::parse_for(ver.as_bytes(), &object_type))
+            .transpose()?;
+
+        Ok(Citation {
+            congress,
+            chamber,
+            object_type,
+            number,
+            ver,
+        })
+    }
+}
+
+/// Parse a formal citation such as `"H.R. 815, 118th Cong."` or `"118th Congress S. J. Res. 1"`.
+///
+/// Periods, commas, and whitespace are removed, along with the words "Cong." and "Congress". An
+/// ordinal Congress like "118th" may come before or after the document type and number. The
+/// normalized citation is then parsed with [`Citation::parse`], so positions in any
+/// `Error::ParseContext` refer to the normalized form rather than to `input`.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{parse_lenient, Citation};
+///
+/// assert_eq!(
+///     Citation::parse("118hr815").unwrap(),
+///     parse_lenient("H.R. 815, 118th Cong.").unwrap()
+/// );
+/// assert_eq!(
+///     Citation::parse("118hconres10").unwrap(),
+///     parse_lenient("118th Congress H. Con. Res. 10").unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will result in an error if the normalized citation fails to parse, including when no Congress
+/// is given, as in `"S. 5"`.
+pub fn parse_lenient(input: &str) -> Result<Citation> {
+    let mut words: Vec<String> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(|word| word.replace('.', ""))
+        .filter(|word| {
+            !word.is_empty()
+                && !word.eq_ignore_ascii_case("cong")
+                && !word.eq_ignore_ascii_case("congress")
+        })
+        .collect();
+    let is_ordinal = |word: &String| {
+        word.len() > 2
+            && word.is_char_boundary(word.len() - 2)
+            && word[..word.len() - 2]
+                .bytes()
+                .all(|byte| byte.is_ascii_digit())
+            && is_ordinal_suffix(&word.as_bytes()[word.len() - 2..])
+    };
+    if let Some(position) = words.iter().position(is_ordinal) {
+        let congress = words.remove(position);
+        words.insert(0, congress);
+    }
+    Citation::parse(&words.concat())
+}
+
+/// Parse a bill or resolution cited in the Government Publishing Office format, such as
+/// `"H.J.Res.1"` or `"S.Con.Res. 10"`, in the given Congress.
+///
+/// The designation must be exactly one of those returned by `CongObjectType::gpo_designation`,
+/// and is followed by the number, optionally after a single space. GPO citations do not name
+/// the Congress, so it is passed separately. For looser formats, see [`parse_lenient`].
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{parse_gpo_format, Citation};
+///
+/// assert_eq!(
+///     Citation::parse("118hjres1").unwrap(),
+///     parse_gpo_format("H.J.Res.1", 118).unwrap()
+/// );
+/// assert!(parse_gpo_format("HJRes1", 118).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will result in `Error::UnknownCongObjectType` if the designation is not recognized, or in an
+/// error if the number or Congress fails the same validation as [`Citation::from_parts`].
+pub fn parse_gpo_format(input: &str, congress: u64) -> Result<Citation> {
+    let split = input.rfind('.').ok_or(Error::UnknownCongObjectType)? + 1;
+    let (designation, number) = input.split_at(split);
+    let object_type = CongObjectType::iter()
+        .find(|object_type| object_type.gpo_designation() == Some(designation))
+        .ok_or(Error::UnknownCongObjectType)?;
+    let number = parse_number(number.strip_prefix(' ').unwrap_or(number))?;
+    Citation::from_parts(congress, object_type.chamber(), object_type, number)
+}
+
+/// Parse a citation, using the given Congress if the citation does not begin with one.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{parse_with_default_congress, Citation};
+///
+/// assert_eq!(
+///     Citation::parse("118hr815").unwrap(),
+///     parse_with_default_congress("hr815", 118).unwrap()
+/// );
+/// assert_eq!(
+///     Citation::parse("117hr815").unwrap(),
+///     parse_with_default_congress("117hr815", 118).unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Will result in the same errors as [`Citation::parse`], with positions in any
+/// `Error::ParseContext` referring to `input`. An invalid default Congress is reported at
+/// position 0.
+pub fn parse_with_default_congress(input: &str, congress: u64) -> Result<Citation> {
+    if !Citation::tokenize(input).congress.is_empty() {
+        return Citation::parse(input);
+    }
+    let congress = congress.to_string();
+    Citation::parse(&format!("{congress}{input}")).map_err(|error| match error {
+        Error::ParseContext {
+            position,
+            fragment,
+            source,
+        } => Error::ParseContext {
+            position: position.saturating_sub(congress.len()),
+            fragment,
+            source,
+        },
+        error => error,
+    })
+}
+
+/// Parse a citation, accepting only its canonical form, e.g. `"118hr815ih"`.
+///
+/// [`Citation::parse`] tolerates hyphens, ordinal suffixes, leading zeros, and trailing input
+/// after the citation. This rejects all of them, which suits validating citations entered in
+/// forms. Letter case is not significant.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{parse_strict, Error};
+///
+/// assert!(parse_strict("118hr815ih").is_ok());
+/// assert!(parse_strict("118S5").is_ok());
+/// assert_eq!(Err(Error::NotCanonical), parse_strict("118th-hr-815"));
+/// assert_eq!(Err(Error::NotCanonical), parse_strict("118hr815ih-extra"));
+/// ```
+///
+/// # Errors
+///
+/// Will result in the same errors as [`Citation::parse`], or `Error::NotCanonical` if the input
+/// is a valid citation written in any other form.
+pub fn parse_strict(input: &str) -> Result<Citation> {
+    let citation = Citation::parse(input)?;
+    if citation.to_string().eq_ignore_ascii_case(input) {
+        Ok(citation)
+    } else {
+        Err(Error::NotCanonical)
+    }
+}
+
+/// Check whether the input is a valid citation, as accepted by [`Citation::parse`].
+///
+/// Each part of the citation is validated, but no `Citation` is built and no error context is
+/// recorded, which makes this cheaper than parsing for filtering large inputs.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::validate;
+///
+/// assert!(validate("118hr815ih"));
+/// assert!(!validate("118xyz999"));
+/// ```
+pub fn validate(input: &str) -> bool {
+    let bytes = Citation::tokenize(input);
+    let chamber = Chamber::parse(bytes.chamber);
+    Congress::parse(&bytes.congress).is_ok()
+        && CongObjectType::parse(&bytes.object_type, &chamber).is_ok_and(|object_type| {
+            bytes
+                .ver
+                .as_deref()
+                .is_none_or(|ver| Version::parse_for(ver, &object_type).is_ok())
+        })
+        && core::str::from_utf8(&bytes.number).is_ok_and(|number| parse_number(number).is_ok())
+}
+
+/// Parse each of the inputs, returning a result for each in the same order.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::parse_many;
+///
+/// let results = parse_many(&["118hr815", "118xx1"]);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn parse_many(inputs: &[&str]) -> Vec<Result<Citation>> {
+    inputs.iter().map(|input| Citation::parse(input)).collect()
+}
+
+/// Parse each of the inputs in parallel, returning a result for each in the same order.
+///
+/// This is [`parse_many`] spread across Rayon's thread pool, for large batches of citations.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::parse_many_parallel;
+///
+/// let results = parse_many_parallel(&["118hr815", "118xx1"]);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+#[cfg(feature = "parallel")]
+pub fn parse_many_parallel(inputs: &[&str]) -> Vec<Result<Citation>> {
+    use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+    inputs
+        .par_iter()
+        .map(|input| Citation::parse(input))
+        .collect()
+}
+
+/// Parse all of the inputs, stopping at the first that fails.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::parse_all;
+///
+/// assert_eq!(2, parse_all(&["118hr815", "118s5"]).unwrap().len());
+/// assert!(parse_all(&["118hr815", "118xx1"]).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will result in the error of the first input that fails to parse.
+pub fn parse_all(inputs: &[&str]) -> Result<Vec<Citation>> {
+    inputs.iter().map(|input| Citation::parse(input)).collect()
+}
+
+/// Parse each of the inputs, skipping any that fail.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{parse_filter, Citation};
+///
+/// let citations = parse_filter(&["118hr815", "118xx1", "118s5"]);
+/// assert_eq!(
+///     vec![
+///         Citation::parse("118hr815").unwrap(),
+///         Citation::parse("118s5").unwrap()
+///     ],
+///     citations
+/// );
+/// ```
+pub fn parse_filter(inputs: &[&str]) -> Vec<Citation> {
+    inputs
+        .iter()
+        .filter_map(|input| Citation::parse(input).ok())
+        .collect()
+}
+
+/// Find and parse the citations embedded in a piece of text.
+///
+/// This collects the citations found by a [`CitationScanner`].
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{extract_citations, Citation};
+///
+/// let citations = extract_citations("See 118hr815, as amended by 118samdt1.");
+/// assert_eq!(
+///     vec![
+///         Citation::parse("118hr815").unwrap(),
+///         Citation::parse("118samdt1").unwrap()
+///     ],
+///     citations
+/// );
+/// ```
+pub fn extract_citations(input: &str) -> Vec<Citation> {
+    extract_citations_with_spans(input)
+        .into_iter()
+        .map(|(citation, _)| citation)
+        .collect()
+}
+
+/// Find and parse the citations embedded in a piece of text, along with the byte range each
+/// occupies in the text.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::extract_citations_with_spans;
+///
+/// let text = "See 118hr815.";
+/// let (citation, span) = &extract_citations_with_spans(text)[0];
+/// assert_eq!("118hr815", &text[span.clone()]);
+/// assert_eq!(815, citation.number());
+/// ```
+pub fn extract_citations_with_spans(input: &str) -> Vec<(Citation, Range<usize>)> {
+    CitationScanner::new(input)
+        .map(|(span, citation)| (citation, span))
+        .collect()
+}
+
+/// Citations serialize to their compact string form, e.g. `"118hr815ih"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Citation {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Citations deserialize from their string form, with the same validation as `Citation::parse`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Citation {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        Self::parse(&input).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Build a citation from string parts keyed `"congress"`, `"chamber"`, `"type"`, `"number"`,
+/// and optionally `"version"`, as found in query parameters and API responses.
+///
+/// The chamber is `"house"` or `"senate"`, or the letter `"h"` or `"s"`, and the type is a code
+/// that starts with its chamber letter, as accepted by `CongObjectType::try_from`. Values are
+/// case-insensitive.
+///
+/// Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use capitol::Citation;
+///
+/// let parts = HashMap::from(
+///     [("congress", "118"), ("chamber", "House"), ("type", "HR"), ("number", "815")]
+///         .map(|(key, value)| (key.to_string(), value.to_string())),
+/// );
+/// assert_eq!(Citation::parse("118hr815").unwrap(), Citation::try_from(parts).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Will result in `Error::MissingField` if a required key is absent, or in an error if any part
+/// fails the same validation as [`Citation::from_parts_with_version`].
+#[cfg(feature = "std")]
+impl TryFrom<std::collections::HashMap<String, String>> for Citation {
+    type Error = Error;
+
+    fn try_from(parts: std::collections::HashMap<String, String>) -> Result<Self> {
+        let part = |key: &'static str| {
+            parts
+                .get(key)
+                .map(String::as_str)
+                .ok_or(Error::MissingField(key))
+        };
+        let congress = Congress::parse(part("congress")?.as_bytes())?;
+        let chamber = match part("chamber")?.to_ascii_lowercase().as_str() {
+            "house" | "h" => Chamber::House,
+            "senate" | "s" => Chamber::Senate,
+            _ => return Err(Error::InvalidChamber),
+        };
+        let object_type = CongObjectType::try_from(part("type")?)?;
+        let number = parse_number(part("number")?)?;
+        let ver = parts.get("version").map(String::as_str);
+        Citation::from_parts_with_version(congress.0, chamber, object_type, number, ver)
+    }
+}
+
+/// Decode a citation from the binary layout produced by [`Citation::to_bytes`].
+///
+/// # Errors
+///
+/// Will result in `Error::InvalidBytes` if the input is not twelve bytes long or names an
+/// unknown chamber, object type, or version, or in an error if the decoded parts fail the same
+/// validation as [`Citation::from_parts`].
+impl TryFrom<&[u8]> for Citation {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        let bytes: &[u8; 12] = bytes.try_into().map_err(|_| Error::InvalidBytes)?;
+        let congress = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let chamber = match bytes[2] {
+            0 => Chamber::House,
+            1 => Chamber::Senate,
+            _ => return Err(Error::InvalidBytes),
+        };
+        let object_type = CongObjectType::from_binary_tag(bytes[3]).ok_or(Error::InvalidBytes)?;
+        let number = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let ver = &bytes[8..];
+        let len = ver.iter().position(|&b| b == 0).unwrap_or(ver.len());
+        if ver[len..].iter().any(|&b| b != 0) {
+            return Err(Error::InvalidBytes);
+        }
+        let ver = match &ver[..len] {
+            [] => None,
+            code if BILL_VERSIONS.contains(&code) => {
+                Some(core::str::from_utf8(code).expect("versions are ASCII"))
+            }
+            _ => return Err(Error::InvalidBytes),
+        };
+        Citation::from_parts_with_version(
+            congress.into(),
+            chamber,
+            object_type,
+            number as usize,
+            ver,
+        )
+    }
+}
+
+/// Citations convert to their Congress.gov URL, as returned by `Citation::to_url`.
+#[cfg(feature = "url")]
+impl From<&Citation> for url::Url {
+    fn from(citation: &Citation) -> Self {
+        url::Url::parse(&citation.to_url()).expect("Congress.gov URLs are always valid")
+    }
+}
+
+#[cfg(feature = "url")]
+impl From<Citation> for url::Url {
+    fn from(citation: Citation) -> Self {
+        Self::from(&citation)
+    }
+}
+
+/// An ordered collection of citations.
+///
+/// A `CitationList` can be collected from an iterator of citations, and dereferences to a slice
+/// of them.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{Chamber, Citation, CitationList};
+///
+/// let mut list = CitationList::new();
+/// list.extend(["118hr815", "118s5"].iter().map(|s| s.parse::<Citation>().unwrap()));
+/// assert_eq!(2, list.as_slice().len());
+///
+/// let list: CitationList = capitol::parse_filter(&["118hr815", "117hres1", "118s5"])
+///     .into_iter()
+///     .collect();
+/// assert_eq!(1, list.filter_by_chamber(Chamber::House).bills_only().len());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CitationList(Vec<Citation>);
+
+impl CitationList {
+    /// Create an empty `CitationList`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the citations in the list as a slice.
+    pub fn as_slice(&self) -> &[Citation] {
+        &self.0
+    }
+
+    /// Get the citations to documents of the given chamber.
+    pub fn filter_by_chamber(&self, chamber: Chamber) -> Self {
+        self.filter(|citation| citation.chamber == chamber)
+    }
+
+    /// Get the citations to documents of the given Congress.
+    pub fn filter_by_congress(&self, congress: u64) -> Self {
+        self.filter(|citation| citation.congress.0 == congress)
+    }
+
+    /// Get the citations to bills, public or private.
+    pub fn bills_only(&self) -> Self {
+        self.filter(|citation| citation.object_type.is_bill())
+    }
+
+    /// Convert each citation to its Congress.gov URL, as with [`Citation::to_url`].
+    pub fn to_urls(&self) -> Vec<String> {
+        self.0.iter().map(Citation::to_url).collect()
+    }
+
+    fn filter(&self, predicate: impl Fn(&Citation) -> bool) -> Self {
+        self.0
+            .iter()
+            .filter(|citation| predicate(citation))
+            .cloned()
+            .collect()
+    }
+}
+
+impl FromIterator<Citation> for CitationList {
+    fn from_iter<T: IntoIterator<Item = Citation>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for CitationList {
+    type Item = Citation;
+    type IntoIter = alloc::vec::IntoIter<Citation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CitationList {
+    type Item = &'a Citation;
+    type IntoIter = core::slice::Iter<'a, Citation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl core::ops::Deref for CitationList {
+    type Target = [Citation];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Extend<Citation> for CitationList {
+    fn extend<T: IntoIterator<Item = Citation>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a Citation> for CitationList {
+    fn extend<T: IntoIterator<Item = &'a Citation>>(&mut self, iter: T) {
+        self.0.extend(iter.into_iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_mut_passed, clippy::needless_borrow)]
+    fn test_tokenize_no_ver_house_bill() {
+        let mut input = "118hr8070";
+        let expected = CiteBytes {
+            congress: b"118".to_vec(),
+            chamber: b'h',
+            object_type: b"r".to_vec(),
+            number: b"8070".to_vec(),
+            ver: None,
+        };
+        let result = Citation::tokenize(&mut input);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_parse_no_ver_house_bill() {
+        let input = "118hr8070";
+        let expected = Citation {
+            congress: Congress(118),
+            chamber: Chamber::House,
+            object_type: CongObjectType::HouseBill,
+            number: 8070,
+            ver: None,
+        };
+        let result = input.parse::<Citation>();
+        assert_eq!(expected, result.unwrap());
+    }
+
+    #[test]
+    fn test_parse_house_bill() {
+        let input = "118hrpt529";
+        let expected = Citation {
+            congress: Congress(118),
+            chamber: Chamber::House,
+            object_type: CongObjectType::HouseReport,
+            number: 529,
+            ver: None,
+        };
+        let result = input.parse::<Citation>();
+        assert_eq!(expected, result.unwrap());
+    }
+
+    #[test]
+    fn test_parse_senate_bill() {
+        let input = "118srpt17";
+        let expected = Citation {
+            congress: Congress(118),
+            chamber: Chamber::Senate,
+            object_type: CongObjectType::SenateReport,
+            number: 17,
+            ver: None,
+        };
+        let result = input.parse::<Citation>();
+        assert_eq!(expected, result.unwrap());
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_mut_passed, clippy::needless_borrow)]
+    fn test_tokenize_no_ver_senate_bill() {
         let mut input = "118s5";
         let expected = CiteBytes {
             congress: b"118".to_vec(),
             chamber: b's',
             object_type: Vec::new(),
-            number: b"5".to_vec(),
+            number: b"5".to_vec(),
+            ver: None,
+        };
+        let result = Citation::tokenize(&mut input);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_mut_passed, clippy::needless_borrow)]
+    fn test_tokenize_with_ver_house_bill() {
+        let mut input = "118hr555ih";
+        let expected = CiteBytes {
+            congress: b"118".to_vec(),
+            chamber: b'h',
+            object_type: b"r".to_vec(),
+            number: b"555".to_vec(),
+            ver: Some(b"ih".to_vec()),
+        };
+        let result = Citation::tokenize(&mut input);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_mut_passed, clippy::needless_borrow)]
+    fn test_tokenize_with_ver_senate_bill() {
+        let mut input = "118s17is";
+        let expected = CiteBytes {
+            congress: b"118".to_vec(),
+            chamber: b's',
+            object_type: Vec::new(),
+            number: b"17".to_vec(),
+            ver: Some(b"is".to_vec()),
+        };
+        let result = Citation::tokenize(&mut input);
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_house_bill_to_url() {
+        let input = "118hr529";
+        let expected = "https://www.congress.gov/bill/118th-congress/house-bill/529";
+        let citation = input.parse::<Citation>().unwrap();
+        let result = citation.to_url();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_house_bill_with_ver_to_url() {
+        let input = "118hr529ih";
+        let expected = "https://www.congress.gov/bill/118th-congress/house-bill/529/text/ih";
+        let citation = input.parse::<Citation>().unwrap();
+        let result = citation.to_url();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_house_report_to_url() {
+        let input = "118hrpt529";
+        let expected =
+            "https://www.congress.gov/congressional-report/118th-congress/house-report/529";
+        let citation = input.parse::<Citation>().unwrap();
+        let result = citation.to_url();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_get_version() {
+        let input = "118hr529ih";
+        let expected = Some("ih");
+        let citation = input.parse::<Citation>().unwrap();
+        let result = citation.version();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_estimated_max_bill_number() {
+        assert_eq!(
+            Some(7321),
+            Citation::estimated_max_bill_number(110, &CongObjectType::HouseBill)
+        );
+        assert_eq!(
+            Some(290),
+            Citation::estimated_max_bill_number(118, &CongObjectType::SenateReport)
+        );
+        assert_eq!(
+            None,
+            Citation::estimated_max_bill_number(109, &CongObjectType::HouseBill)
+        );
+        assert_eq!(
+            None,
+            Citation::estimated_max_bill_number(119, &CongObjectType::SenateBill)
+        );
+    }
+
+    #[test]
+    fn test_has_plausible_number_for_congress() {
+        let citation = "118s5600".parse::<Citation>().unwrap();
+        assert!(citation.has_plausible_number_for_congress());
+        let citation = "118s5601".parse::<Citation>().unwrap();
+        assert!(!citation.has_plausible_number_for_congress());
+        let citation = "99hr99999".parse::<Citation>().unwrap();
+        assert!(citation.has_plausible_number_for_congress());
+    }
+
+    #[test]
+    fn test_chamber_abbreviation() {
+        let citation = "118hrpt529".parse::<Citation>().unwrap();
+        assert_eq!("h", citation.chamber_abbreviation());
+        let citation = "118SRES1".parse::<Citation>().unwrap();
+        assert_eq!("s", citation.chamber_abbreviation());
+    }
+
+    #[test]
+    fn test_to_bill_summary_url() {
+        let citation = "118sjres1is".parse::<Citation>().unwrap();
+        let expected =
+            "https://www.congress.gov/bill/118th-congress/senate-joint-resolution/1/summary";
+        assert_eq!(Some(expected.to_string()), citation.to_bill_summary_url());
+        let citation = "118hrpt529".parse::<Citation>().unwrap();
+        assert_eq!(None, citation.to_bill_summary_url());
+    }
+
+    #[test]
+    fn test_to_all_info_url() {
+        let citation = "118s5is".parse::<Citation>().unwrap();
+        let expected = "https://www.congress.gov/bill/118th-congress/senate-bill/5/all-info";
+        assert_eq!(expected, citation.to_all_info_url());
+    }
+
+    #[test]
+    fn test_citation_list_extend_from_slice() {
+        let citations = [
+            "118hr815".parse::<Citation>().unwrap(),
+            "118s5".parse::<Citation>().unwrap(),
+        ];
+        let mut list = CitationList::new();
+        list.extend(&citations);
+        assert_eq!(&citations, list.as_slice());
+    }
+
+    #[test]
+    fn test_citation_list_extend_from_map() {
+        let mut list = CitationList::new();
+        list.extend(
+            ["118hr815", "118hrpt529"]
+                .iter()
+                .map(|s| s.parse::<Citation>().unwrap()),
+        );
+        assert_eq!(2, list.as_slice().len());
+        assert_eq!(CongObjectType::HouseReport, list.as_slice()[1].object_type);
+    }
+
+    #[test]
+    fn test_to_enrolled_bill_url() {
+        let expected = Some("https://www.congress.gov/bill/118th-congress/senate-bill/5/text/enr");
+        let citation = "118s5".parse::<Citation>().unwrap();
+        assert_eq!(expected, citation.to_enrolled_bill_url().as_deref());
+        let citation = "118s5is".parse::<Citation>().unwrap();
+        assert_eq!(expected, citation.to_enrolled_bill_url().as_deref());
+        let citation = "118sres5".parse::<Citation>().unwrap();
+        assert_eq!(None, citation.to_enrolled_bill_url());
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_citation_into_url() {
+        let citation = Citation::parse("118hr815ih").unwrap();
+        let mut url = url::Url::from(&citation);
+        assert_eq!(citation.to_url(), url.as_str());
+        url.query_pairs_mut().append_pair("s", "1");
+        assert_eq!(
+            "https://www.congress.gov/bill/118th-congress/house-bill/815/text/ih?s=1",
+            url.as_str()
+        );
+        assert_eq!(url::Url::from(citation.clone()), url::Url::from(&citation));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_citation() {
+        let result: Citation = serde_json::from_str("\"118hr815\"").unwrap();
+        assert_eq!(Citation::parse("118hr815").unwrap(), result);
+        assert!(serde_json::from_str::<Citation>("\"118hr815xx\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_version() {
+        let version: Version = serde_json::from_str("\"IH\"").unwrap();
+        assert_eq!(Version::new("ih").unwrap(), version);
+        assert_eq!("\"ih\"", serde_json::to_string(&version).unwrap());
+        assert!(serde_json::from_str::<Version>("\"xx\"").is_err());
+        assert!(serde_json::from_str::<Version>("\"\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_citation_round_trip() {
+        for input in ["118hr815", "118s5is", "118hconres10", "118srpt17"] {
+            let citation = Citation::parse(input).unwrap();
+            let json = serde_json::to_string(&citation).unwrap();
+            assert_eq!(format!("\"{input}\""), json);
+            assert_eq!(citation, serde_json::from_str::<Citation>(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_display_all_object_types() {
+        for input in [
+            "118s5",
+            "118hr815",
+            "118sprivb1",
+            "118hprivb1",
+            "118sres1",
+            "118hres1",
+            "118sconres1",
+            "118hconres1",
+            "118sjres1",
+            "118hjres1",
+            "118hrpt529",
+            "118srpt17",
+        ] {
+            let citation = input.parse::<Citation>().unwrap();
+            assert_eq!(input, citation.to_string());
+        }
+    }
+
+    #[test]
+    fn test_display_normalizes_input() {
+        let citation = "118H815".parse::<Citation>().unwrap();
+        assert_eq!("118hr815", citation.to_string());
+        let citation = "118HJRES1ih".parse::<Citation>().unwrap();
+        assert_eq!("118hjres1ih", citation.to_string());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for input in ["118hr555ih", "118s17is", "118sconres3"] {
+            let citation = input.parse::<Citation>().unwrap();
+            assert_eq!(citation, citation.to_string().parse::<Citation>().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_clone_citation() {
+        let citation = "118hr815ih".parse::<Citation>().unwrap();
+        let cloned = citation.clone();
+        assert_eq!(citation, cloned);
+        let object_type = cloned.object_type;
+        assert_eq!(citation.object_type, object_type);
+    }
+
+    #[test]
+    fn test_citation_as_hash_map_key() {
+        use std::collections::HashMap;
+
+        let mut titles = HashMap::new();
+        titles.insert(
+            "118hr815".parse::<Citation>().unwrap(),
+            "Making emergency supplemental appropriations",
+        );
+        let key = "118HR815".parse::<Citation>().unwrap();
+        assert_eq!(
+            Some(&"Making emergency supplemental appropriations"),
+            titles.get(&key)
+        );
+        assert_eq!(None, titles.get(&"118hr815ih".parse::<Citation>().unwrap()));
+    }
+
+    #[test]
+    fn test_sort_citations() {
+        let mut citations = [
+            "118s5",
+            "118hrpt529",
+            "117s5",
+            "118hr815ih",
+            "118hres3",
+            "118hr815",
+            "118hr9",
+        ]
+        .map(|input| input.parse::<Citation>().unwrap());
+        citations.sort();
+        let sorted = citations.map(|citation| citation.to_string());
+        assert_eq!(
+            [
+                "117s5",
+                "118hr9",
+                "118hr815",
+                "118hr815ih",
+                "118hres3",
+                "118hrpt529",
+                "118s5"
+            ],
+            sorted
+        );
+    }
+
+    #[test]
+    fn test_from_url_round_trip() {
+        for input in [
+            "118hr815",
+            "118hr815ih",
+            "118sconres3",
+            "118hrpt529",
+            "118srpt17",
+        ] {
+            let citation = input.parse::<Citation>().unwrap();
+            assert_eq!(citation, Citation::from_url(&citation.to_url()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_url_ignores_trailing_parts() {
+        let expected = "118s5".parse::<Citation>().unwrap();
+        for url in [
+            "https://www.congress.gov/bill/118th-congress/senate-bill/5/",
+            "https://congress.gov/bill/118th-congress/senate-bill/5/actions",
+            "http://www.congress.gov/bill/118th-congress/senate-bill/5?q=%7B%7D",
+        ] {
+            assert_eq!(expected, Citation::from_url(url).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_url_malformed() {
+        for url in [
+            "https://www.example.com/bill/118th-congress/senate-bill/5",
+            "https://www.congress.gov/bill/118th-congress",
+            "https://www.congress.gov/bill/118th-congress/house-report/529",
+            "https://www.congress.gov/bill/118th/senate-bill/5",
+        ] {
+            assert_eq!(Err(Error::InvalidUrl), Citation::from_url(url));
+        }
+    }
+
+    #[test]
+    fn test_builder_without_chamber() {
+        let citation = CitationBuilder::new()
+            .congress(118)
+            .object_type(CongObjectType::SenateReport)
+            .number(17)
+            .build()
+            .unwrap();
+        assert_eq!("118srpt17".parse::<Citation>().unwrap(), citation);
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let builder = CitationBuilder::new()
+            .congress(118)
+            .object_type(CongObjectType::HouseBill)
+            .number(815);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            builder.clone().congress(1000).build()
+        );
+        assert_eq!(
+            Err(Error::ChamberMismatch),
+            builder.clone().chamber(Chamber::Senate).build()
+        );
+        assert_eq!(
+            Err(Error::InvalidBillVersion),
+            builder.clone().version(Some("xx")).build()
+        );
+        assert_eq!(
+            Err(Error::MissingField("object type")),
+            CitationBuilder::new().congress(118).number(815).build()
+        );
+    }
+
+    #[test]
+    fn test_tokenize_house_amendment() {
+        let input = "118hamdt1";
+        let expected = CiteBytes {
+            congress: b"118".to_vec(),
+            chamber: b'h',
+            object_type: b"amdt".to_vec(),
+            number: b"1".to_vec(),
+            ver: None,
+        };
+        assert_eq!(expected, Citation::tokenize(input));
+    }
+
+    #[test]
+    fn test_parse_amendments() {
+        let citation = "118hamdt1".parse::<Citation>().unwrap();
+        assert_eq!(CongObjectType::HouseAmendment, citation.object_type);
+        assert_eq!(Chamber::House, citation.chamber);
+        let citation = "118SAMDT2".parse::<Citation>().unwrap();
+        assert_eq!(CongObjectType::SenateAmendment, citation.object_type);
+        assert_eq!(Chamber::Senate, citation.chamber);
+    }
+
+    #[test]
+    fn test_amendments_to_url() {
+        let citation = "118hamdt1".parse::<Citation>().unwrap();
+        let expected = "https://www.congress.gov/amendment/118th-congress/house-amendment/1";
+        assert_eq!(expected, citation.to_url());
+        let citation = "118samdt1".parse::<Citation>().unwrap();
+        let expected = "https://www.congress.gov/amendment/118th-congress/senate-amendment/1";
+        assert_eq!(expected, citation.to_url());
+        assert_eq!(citation, Citation::from_url(expected).unwrap());
+        assert_eq!("118samdt1", citation.to_string());
+    }
+
+    #[test]
+    fn test_to_govinfo_url() {
+        let citation = "118sjres1is".parse::<Citation>().unwrap();
+        let expected =
+            "https://www.govinfo.gov/content/pkg/BILLS-118sjres1is/html/BILLS-118sjres1is.htm";
+        assert_eq!(expected, citation.to_govinfo_url().unwrap());
+        let citation = "118hrpt529".parse::<Citation>().unwrap();
+        let expected =
+            "https://www.govinfo.gov/content/pkg/CRPT-118hrpt529/html/CRPT-118hrpt529.htm";
+        assert_eq!(expected, citation.to_govinfo_url().unwrap());
+    }
+
+    #[test]
+    fn test_to_govinfo_url_errors() {
+        let citation = "118hr815".parse::<Citation>().unwrap();
+        assert_eq!(Err(Error::MissingBillVersion), citation.to_govinfo_url());
+        let citation = "118hamdt1".parse::<Citation>().unwrap();
+        assert_eq!(Err(Error::UnknownCongObjectType), citation.to_govinfo_url());
+    }
+
+    #[test]
+    fn test_to_api_url() {
+        for (input, expected) in [
+            ("118hr815ih", "https://api.congress.gov/v3/bill/118/hr/815"),
+            ("118s5", "https://api.congress.gov/v3/bill/118/s/5"),
+            (
+                "118sconres3",
+                "https://api.congress.gov/v3/bill/118/sconres/3",
+            ),
+            (
+                "118srpt17",
+                "https://api.congress.gov/v3/committee-report/118/srpt/17",
+            ),
+            (
+                "118hamdt1",
+                "https://api.congress.gov/v3/amendment/118/hamdt/1",
+            ),
+        ] {
+            let citation = input.parse::<Citation>().unwrap();
+            assert_eq!(expected, citation.to_api_url().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_to_api_url_with_key() {
+        let citation = "118hjres7".parse::<Citation>().unwrap();
+        let expected = "https://api.congress.gov/v3/bill/118/hjres/7?api_key=abc123";
+        assert_eq!(expected, citation.to_api_url_with_key("abc123").unwrap());
+    }
+
+    #[test]
+    fn test_parse_documents() {
+        let citation = "118hdoc1".parse::<Citation>().unwrap();
+        assert_eq!(CongObjectType::HouseDocument, citation.object_type);
+        assert_eq!("118hdoc1", citation.to_string());
+        let citation = "118sdoc7".parse::<Citation>().unwrap();
+        assert_eq!(CongObjectType::SenateDocument, citation.object_type);
+        assert_eq!(Chamber::Senate, citation.chamber);
+    }
+
+    #[test]
+    fn test_documents_to_url() {
+        let citation = "118hdoc1".parse::<Citation>().unwrap();
+        let expected =
+            "https://www.congress.gov/congressional-document/118th-congress/house-document/1";
+        assert_eq!(expected, citation.to_url());
+        assert_eq!(citation, Citation::from_url(expected).unwrap());
+        let expected = "https://www.govinfo.gov/content/pkg/CDOC-118hdoc1/html/CDOC-118hdoc1.htm";
+        assert_eq!(expected, citation.to_govinfo_url().unwrap());
+        assert_eq!(Err(Error::UnknownCongObjectType), citation.to_api_url());
+    }
+
+    #[test]
+    fn test_committee_prints() {
+        let citation = "118hprt1".parse::<Citation>().unwrap();
+        assert_eq!(CongObjectType::HousePrint, citation.object_type);
+        assert_eq!("118hprt1", citation.to_string());
+        let expected =
+            "https://www.congress.gov/congressional-document/118th-congress/house-print/1";
+        assert_eq!(expected, citation.to_url());
+        assert_eq!(citation, Citation::from_url(expected).unwrap());
+        let citation = "118sprt52".parse::<Citation>().unwrap();
+        assert_eq!(CongObjectType::SenatePrint, citation.object_type);
+        assert_eq!(
+            "https://www.congress.gov/congressional-document/118th-congress/senate-print/52",
+            citation.to_url()
+        );
+        assert_eq!(Err(Error::UnknownCongObjectType), citation.to_govinfo_url());
+    }
+
+    #[test]
+    fn test_bill_versions_sorted() {
+        assert!(BILL_VERSIONS.is_sorted());
+        for ver in BILL_VERSIONS {
+            assert!(Version::parse(ver).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_version_description() {
+        assert_eq!(
+            "Introduced in House",
+            Version("ih".to_string()).description()
+        );
+        assert_eq!(Some("Engrossed in Senate"), version_description("es"));
+        assert_eq!(None, version_description("IH"));
+        for ver in BILL_VERSIONS {
+            let ver = Version::parse(ver).unwrap();
+            assert!(!ver.description().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_tokenize_ordinal_congress() {
+        let input = "118thhr815";
+        let expected = CiteBytes {
+            congress: b"118th".to_vec(),
+            chamber: b'h',
+            object_type: b"r".to_vec(),
+            number: b"815".to_vec(),
             ver: None,
         };
-        let result = Citation::tokenize(&mut input);
-        assert_eq!(expected, result);
+        assert_eq!(expected, Citation::tokenize(input));
+    }
+
+    #[test]
+    fn test_parse_ordinal_congress() {
+        let expected = "118hr815".parse::<Citation>().unwrap();
+        assert_eq!(expected, "118thhr815".parse::<Citation>().unwrap());
+        assert_eq!(expected, "118THHR815".parse::<Citation>().unwrap());
+        assert_eq!(Congress(21), "21sts5".parse::<Citation>().unwrap().congress);
+        assert_eq!(Congress(22), "22nds5".parse::<Citation>().unwrap().congress);
+        assert_eq!(
+            Congress(23),
+            "23rdsres1".parse::<Citation>().unwrap().congress
+        );
+    }
+
+    #[test]
+    fn test_tokenize_hyphenated() {
+        let input = "118-HR-815-IH";
+        let expected = CiteBytes {
+            congress: b"118".to_vec(),
+            chamber: b'H',
+            object_type: b"R".to_vec(),
+            number: b"815".to_vec(),
+            ver: Some(b"IH".to_vec()),
+        };
+        assert_eq!(expected, Citation::tokenize(input));
+    }
+
+    #[test]
+    fn test_parse_hyphenated() {
+        for (hyphenated, compact) in [
+            ("118-HR-815", "118hr815"),
+            ("118-S-5", "118s5"),
+            ("118-hconres-3", "118hconres3"),
+            ("118-HR-815-IH", "118hr815ih"),
+            ("118th-SRES-10", "118sres10"),
+        ] {
+            assert_eq!(
+                compact.parse::<Citation>().unwrap(),
+                hyphenated.parse::<Citation>().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_kind_predicates() {
+        for (input, bill, resolution, report, measure) in [
+            ("118hr815", true, false, false, true),
+            ("118s5", true, false, false, true),
+            ("118hres1", false, true, false, true),
+            ("118sconres1", false, true, false, true),
+            ("118hjres1", false, true, false, true),
+            ("118srpt17", false, false, true, false),
+            ("118samdt1", false, false, false, false),
+            ("118hdoc1", false, false, false, false),
+        ] {
+            let citation = input.parse::<Citation>().unwrap();
+            assert_eq!(bill, citation.is_bill(), "{input}");
+            assert_eq!(resolution, citation.is_resolution(), "{input}");
+            assert_eq!(report, citation.is_report(), "{input}");
+            assert_eq!(measure, citation.is_measure(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_with_version() {
+        let citation = "118s5".parse::<Citation>().unwrap();
+        let versioned = citation.with_version("es").unwrap();
+        assert_eq!("118s5es".parse::<Citation>().unwrap(), versioned);
+        assert_eq!(Err(Error::InvalidBillVersion), citation.with_version("xx"));
+    }
+
+    #[test]
+    fn test_version_only_on_measures() {
+        for input in ["118samdt1ih", "118hrpt529ih", "118hdoc1enr"] {
+            assert_eq!(
+                Error::InvalidBillVersion,
+                *Citation::parse(input).unwrap_err().root_cause()
+            );
+            assert!(!validate(input));
+        }
+        assert_eq!(
+            Error::InvalidBillVersion,
+            *parse_strict("118hrpt529ih").unwrap_err().root_cause()
+        );
+        assert_eq!(
+            Err(Error::InvalidBillVersion),
+            Citation::parse("118hrpt529").unwrap().with_version("ih")
+        );
+        assert_eq!(
+            Err(Error::InvalidBillVersion),
+            Citation::from_parts_with_version(
+                118,
+                Chamber::House,
+                CongObjectType::HouseReport,
+                529,
+                Some("enr")
+            )
+        );
+        assert!(Citation::parse("118hjres5enr").unwrap().is_measure());
+    }
+
+    #[test]
+    fn test_without_version() {
+        let citation = "118s5es".parse::<Citation>().unwrap();
+        assert_eq!(None, citation.without_version().version());
+        assert_eq!(
+            citation,
+            citation.without_version().with_version("es").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_version_stage_predicates() {
+        let citation = "118hr815enr".parse::<Citation>().unwrap();
+        assert!(citation.is_enacted());
+        assert!(!citation.is_introduced());
+        let citation = "118hr815ih".parse::<Citation>().unwrap();
+        assert!(!citation.is_enacted());
+        assert!(citation.is_introduced());
+        let citation = "118hr815".parse::<Citation>().unwrap();
+        assert!(!citation.is_enacted());
+        assert!(!citation.is_introduced());
+    }
+
+    #[test]
+    fn test_getters() {
+        let citation = "118sjres7is".parse::<Citation>().unwrap();
+        assert_eq!(118, citation.congress_number());
+        assert_eq!(&Congress(118), citation.congress());
+        assert_eq!(&Chamber::Senate, citation.chamber());
+        assert_eq!(
+            &CongObjectType::SenateJointResolution,
+            citation.object_type()
+        );
+        assert_eq!(7, citation.number());
+        assert_eq!(Some("is"), citation.version());
+    }
+
+    #[test]
+    fn test_extract_citations() {
+        let text = "H.R. 815 (118hr815ih) passed; see also 118-S-5, 118srpt17, and the 5th item.";
+        let expected = ["118hr815ih", "118s5", "118srpt17"]
+            .map(|input| input.parse::<Citation>().unwrap())
+            .to_vec();
+        assert_eq!(expected, extract_citations(text));
+    }
+
+    #[test]
+    fn test_extract_citations_with_spans() {
+        let text = "Compare 118hres1—and 118sres2.";
+        let result = extract_citations_with_spans(text);
+        assert_eq!(2, result.len());
+        assert_eq!("118hres1", &text[result[0].1.clone()]);
+        assert_eq!("118sres2", &text[result[1].1.clone()]);
+        assert!(extract_citations_with_spans("nothing to see in 2024").is_empty());
+    }
+
+    #[test]
+    fn test_error_equality() {
+        let root_cause = |input| Citation::parse(input).unwrap_err().root_cause().clone();
+        #[cfg(feature = "std")]
+        assert_eq!(Error::InvalidCongress, root_cause("999hr1"));
+        assert_eq!(Error::UnknownCongObjectType, root_cause("118hx1"));
+        assert_eq!(Error::InvalidBillNumber, root_cause("118hr"));
+        let overflow = "99999999999999999999999".parse::<usize>().unwrap_err();
+        assert_eq!(
+            Error::ParseInt(overflow),
+            root_cause("118hr99999999999999999999999")
+        );
+    }
+
+    #[test]
+    fn test_parse_context() {
+        let context = |position, fragment: &str, source| {
+            Err(Error::ParseContext {
+                position,
+                fragment: fragment.to_string(),
+                source: Box::new(source),
+            })
+        };
+        assert_eq!(
+            context(3, "xyz", Error::UnknownCongObjectType),
+            Citation::parse("118xyz999")
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            context(0, "999th", Error::InvalidCongress),
+            Citation::parse("999th-hr-1")
+        );
+        assert_eq!(
+            context(8, "xx", Error::InvalidBillVersion),
+            Citation::parse("118hr815xx")
+        );
+        assert_eq!(
+            "invalid citation part 'xyz' at position 3",
+            Citation::parse("118xyz999").unwrap_err().to_string()
+        );
     }
 
     #[test]
-    fn test_tokenize_with_ver_house_bill() {
-        let mut input = "118hr555ih";
-        let expected = CiteBytes {
-            congress: b"118".to_vec(),
-            chamber: b'h',
-            object_type: b"r".to_vec(),
-            number: b"555".to_vec(),
-            ver: Some(b"ih".to_vec()),
+    fn test_from_parts() {
+        let citation =
+            Citation::from_parts(118, Chamber::Senate, CongObjectType::SenateReport, 17).unwrap();
+        assert_eq!(Citation::parse("118srpt17").unwrap(), citation);
+        assert_eq!(
+            Err(Error::ChamberMismatch),
+            Citation::from_parts(118, Chamber::Senate, CongObjectType::HouseBill, 1)
+        );
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            Citation::from_parts(0, Chamber::House, CongObjectType::HouseBill, 1)
+        );
+        assert_eq!(
+            Err(Error::InvalidBillVersion),
+            Citation::from_parts_with_version(
+                118,
+                Chamber::House,
+                CongObjectType::HouseBill,
+                1,
+                Some("xx")
+            )
+        );
+    }
+
+    #[test]
+    fn test_private_bills() {
+        let citation = Citation::parse("118hprivb12ih").unwrap();
+        assert_eq!(&CongObjectType::HousePrivateBill, citation.object_type());
+        assert!(citation.is_bill());
+        assert_eq!(
+            "https://www.congress.gov/bill/118th-congress/house-bill/12/text/ih",
+            citation.to_url()
+        );
+        assert_eq!(
+            "https://api.congress.gov/v3/bill/118/hr/12",
+            citation.to_api_url().unwrap()
+        );
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/BILLS-118hr12ih/html/BILLS-118hr12ih.htm",
+            citation.to_govinfo_url().unwrap()
+        );
+        let citation = Citation::parse("118SPRIVB3").unwrap();
+        assert_eq!(&CongObjectType::SenatePrivateBill, citation.object_type());
+        assert_eq!("118sprivb3", citation.to_string());
+    }
+
+    #[test]
+    fn test_to_text_url() {
+        let citation = Citation::parse("118s5es").unwrap();
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/BILLS-118s5es/xml/BILLS-118s5es.xml",
+            citation.to_text_url(TextFormat::Xml).unwrap()
+        );
+        assert_eq!(
+            citation.to_govinfo_url(),
+            citation.to_text_url(TextFormat::Html)
+        );
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CRPT-118hrpt529/pdf/CRPT-118hrpt529.pdf",
+            Citation::parse("118hrpt529")
+                .unwrap()
+                .to_text_url(TextFormat::Pdf)
+                .unwrap()
+        );
+        assert_eq!(
+            Err(Error::MissingBillVersion),
+            Citation::parse("118s5")
+                .unwrap()
+                .to_text_url(TextFormat::Pdf)
+        );
+    }
+
+    #[test]
+    fn test_batch_parsing() {
+        let inputs = ["118hr815", "0hr1", "118s5", "118hr"];
+        let results = parse_many(&inputs);
+        assert_eq!(inputs.len(), results.len());
+        assert_eq!(
+            vec![true, false, true, false],
+            results.iter().map(Result::is_ok).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            &Error::InvalidCongress,
+            parse_all(&inputs).unwrap_err().root_cause()
+        );
+        assert_eq!(2, parse_filter(&inputs).len());
+        assert!(parse_all(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_version_predicates() {
+        let version = |code: &str| code.parse::<Version>().unwrap();
+        assert!(version("IS").is_introduced());
+        assert!(version("eh").is_passed_house());
+        assert!(version("eah").is_passed_house());
+        assert!(version("es").is_passed_senate());
+        assert!(version("eas").is_passed_senate());
+        assert!(!version("eas").is_passed_house());
+        assert!(version("enr").is_enrolled());
+        assert!(!version("eh").is_enrolled());
+        assert_eq!(Err(Error::InvalidBillVersion), "xx".parse::<Version>());
+    }
+
+    #[test]
+    fn test_invalid_bill_number() {
+        let root_cause = |input| Citation::parse(input).unwrap_err().root_cause().clone();
+        assert_eq!(Error::InvalidBillNumber, root_cause("118hr0"));
+        assert_eq!(Error::InvalidBillNumber, root_cause("118hr-ih"));
+        assert_eq!(
+            "bill number must be a positive integer",
+            Error::InvalidBillNumber.to_string()
+        );
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            Citation::from_url("https://www.congress.gov/bill/118th-congress/house-bill/0")
+        );
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            Citation::from_parts(118, Chamber::House, CongObjectType::HouseBill, 0)
+        );
+    }
+
+    #[test]
+    fn test_teens_congress_urls() {
+        for (input, ordinal) in [("11hr1", "11th"), ("12s2", "12th"), ("13hres3", "13th")] {
+            let url = Citation::parse(input).unwrap().to_url();
+            assert!(url.contains(&format!("/{ordinal}-congress/")), "{url}");
+            assert_eq!(
+                Ok(Citation::parse(input).unwrap()),
+                Citation::from_url(&url)
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_citation_string_round_trip() {
+        for input in [
+            "118HR815",
+            "118-S-5-IS",
+            "118thhprivb1",
+            "118sconres3ats",
+            "118srpt17",
+            "118HAMDT4",
+            "118sdoc2",
+            "21sts5enr",
+        ] {
+            let citation = Citation::parse(input).unwrap();
+            let canonical = citation.to_citation_string();
+            assert_eq!(canonical.to_ascii_lowercase(), canonical);
+            assert!(canonical.chars().all(|ch| ch.is_ascii_alphanumeric()));
+            assert_eq!(citation, Citation::parse(&canonical).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_to_govinfo_report_url() {
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CRPT-118srpt17/html/CRPT-118srpt17.htm",
+            Citation::parse("118srpt17")
+                .unwrap()
+                .to_govinfo_report_url()
+                .unwrap()
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            Citation::parse("118hr815ih")
+                .unwrap()
+                .to_govinfo_report_url()
+        );
+    }
+
+    #[test]
+    fn test_govinfo_report_url_ignores_version() {
+        let citation = Citation {
+            ver: Some(Version("ih".to_string())),
+            ..Citation::parse("118hrpt529").unwrap()
         };
-        let result = Citation::tokenize(&mut input);
-        assert_eq!(expected, result);
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CRPT-118hrpt529/html/CRPT-118hrpt529.htm",
+            citation.to_govinfo_report_url().unwrap()
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            Citation::parse("118erpt1").unwrap().to_govinfo_report_url()
+        );
     }
 
     #[test]
-    fn test_tokenize_with_ver_senate_bill() {
-        let mut input = "118s17is";
-        let expected = CiteBytes {
-            congress: b"118".to_vec(),
-            chamber: b's',
-            object_type: Vec::new(),
-            number: b"17".to_vec(),
-            ver: Some(b"is".to_vec()),
+    fn test_executive_reports() {
+        let citation = Citation::parse("118erpt1").unwrap();
+        assert_eq!(
+            &CongObjectType::SenateExecutiveReport,
+            citation.object_type()
+        );
+        assert_eq!(&Chamber::Senate, citation.chamber());
+        assert_eq!("118erpt1", citation.to_string());
+        assert_eq!(
+            "https://www.congress.gov/executive-report/118th-congress/1",
+            citation.to_url()
+        );
+        assert_eq!(Ok(citation.clone()), Citation::from_url(&citation.to_url()));
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CRPT-118erpt1/html/CRPT-118erpt1.htm",
+            citation.to_govinfo_url().unwrap()
+        );
+        assert_eq!(Err(Error::UnknownCongObjectType), citation.to_api_url());
+        assert_eq!(
+            &Error::UnknownCongObjectType,
+            Citation::parse("118herpt1").unwrap_err().root_cause()
+        );
+    }
+
+    #[test]
+    fn test_search_url() {
+        assert_eq!(
+            "https://www.congress.gov/search?q=%7B%22congress%22%3A%22117%22%2C%22chamber%22%3A%22Senate%22%7D",
+            Citation::parse("117sres5").unwrap().search_url()
+        );
+        assert_eq!("a-b_c.d~%20%2F%25", percent_encode("a-b_c.d~ /%"));
+    }
+
+    #[test]
+    fn test_congress_with_zero_digits() {
+        assert_eq!(Congress(110), Citation::parse("110hr1").unwrap().congress);
+        assert_eq!(
+            Congress(100),
+            Citation::parse("100th-s-2").unwrap().congress
+        );
+        assert_eq!(Congress(18), Citation::parse("018hr815").unwrap().congress);
+        let root_cause = |input| Citation::parse(input).unwrap_err().root_cause().clone();
+        assert_eq!(Error::InvalidCongress, root_cause("0hr1"));
+        assert_eq!(Error::MissingCongress, root_cause("hr815"));
+        #[cfg(feature = "std")]
+        assert_eq!(Error::InvalidCongress, root_cause("120hr1"));
+    }
+
+    #[test]
+    fn test_to_propublica_url() {
+        assert_eq!(
+            Ok("https://api.propublica.org/congress/v1/117/senate/bills/sjres3.json".to_string()),
+            Citation::parse("117sjres3ih").unwrap().to_propublica_url()
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            Citation::parse("118hrpt529").unwrap().to_propublica_url()
+        );
+    }
+
+    #[test]
+    fn test_fmt_long() {
+        assert_eq!(
+            "Senate Executive Report 2 (113th Congress)",
+            Citation::parse("113erpt2").unwrap().fmt_long()
+        );
+        assert_eq!(
+            "House Joint Resolution 7 (21st Congress, Enrolled Bill)",
+            Citation::parse("21hjres7enr").unwrap().fmt_long()
+        );
+    }
+
+    #[test]
+    fn test_to_html_link() {
+        let citation = Citation::parse("118hr815").unwrap();
+        assert_eq!(
+            r#"<a href="https://www.congress.gov/bill/118th-congress/house-bill/815">House Bill 815 (118th Congress)</a>"#,
+            citation.to_html_link(None)
+        );
+        assert_eq!(
+            r#"<a href="https://www.congress.gov/bill/118th-congress/house-bill/815">&lt;script&gt;&quot;x&quot; &amp; &#39;y&#39;&lt;/script&gt;</a>"#,
+            citation.to_html_link(Some(r#"<script>"x" & 'y'</script>"#))
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let parse = |input| parse_lenient(input).map(|citation| citation.to_string());
+        assert_eq!(Ok("118hr815".to_string()), parse("H.R. 815, 118th Cong."));
+        assert_eq!(Ok("118hr815".to_string()), parse("118th Cong., H.R. 815"));
+        assert_eq!(Ok("118s5".to_string()), parse("S. 5, 118th Congress"));
+        assert_eq!(
+            Ok("118hconres10".to_string()),
+            parse("H. Con. Res. 10, 118th Cong.")
+        );
+        assert_eq!(
+            Ok("117sjres1".to_string()),
+            parse("S. J. Res. 1, 117th Cong.")
+        );
+        assert_eq!(
+            Ok("118hr815ih".to_string()),
+            parse("H.R. 815 IH, 118th Cong.")
+        );
+        assert_eq!(Ok("118hr815".to_string()), parse("118hr815"));
+        assert_eq!(
+            Err(Error::MissingCongress),
+            parse_lenient("S. 5").map_err(|e| e.root_cause().clone())
+        );
+        assert!(parse_lenient("").is_err());
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        for input in [
+            "118hr815",
+            "118s5",
+            "118HR815IH",
+            "118sres12ats",
+            "118hrpt17",
+            "118erpt1",
+        ] {
+            assert!(parse_strict(input).is_ok(), "{input}");
+        }
+        for input in [
+            "118-hr-815",
+            "118th-hr-815",
+            "018hr815",
+            "118hr815ih-extra",
+            "118hr815 ",
+        ] {
+            assert_eq!(Err(Error::NotCanonical), parse_strict(input), "{input}");
+        }
+        assert_eq!(
+            Error::UnknownCongObjectType,
+            *parse_strict("118xyz999").unwrap_err().root_cause()
+        );
+    }
+
+    #[test]
+    fn test_conference_report() {
+        let citation = Citation::parse("118hconf5").unwrap();
+        assert_eq!(
+            &CongObjectType::HouseConferenceReport,
+            citation.object_type()
+        );
+        assert_eq!("118hconf5", citation.to_string());
+        assert_eq!(
+            "https://www.congress.gov/congressional-report/118th-congress/house-report/5",
+            citation.to_url()
+        );
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CRPT-118hrpt5/html/CRPT-118hrpt5.htm",
+            citation.to_govinfo_report_url().unwrap()
+        );
+        assert_eq!(
+            "https://api.congress.gov/v3/committee-report/118/hrpt/5",
+            citation.to_api_url().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bill_versions_descriptions() {
+        assert_eq!(BILL_VERSIONS.len(), BILL_VERSIONS_DESCRIPTIONS.len());
+        for (ver, (code, description)) in BILL_VERSIONS.iter().zip(BILL_VERSIONS_DESCRIPTIONS) {
+            assert_eq!(*ver, code.as_bytes());
+            assert_eq!(Some(*description), version_description(code));
+        }
+    }
+
+    #[test]
+    fn test_tab_urls() {
+        let citation = Citation::parse("118hr815ih").unwrap();
+        let base = "https://www.congress.gov/bill/118th-congress/house-bill/815";
+        assert_eq!(format!("{base}/actions"), citation.actions_url());
+        assert_eq!(format!("{base}/cosponsors"), citation.cosponsors_url());
+        assert_eq!(format!("{base}/committees"), citation.committees_url());
+        assert_eq!(format!("{base}/text"), citation.text_url());
+        assert_eq!(format!("{base}/amendments"), citation.amendments_url());
+        assert_eq!(
+            "https://www.congress.gov/congressional-report/118th-congress/house-report/529/text",
+            Citation::parse("118hrpt529").unwrap().text_url()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let inputs = [
+            "118hr815",
+            "118th-HR-815-IH",
+            "118erpt1",
+            "118hconf5",
+            "118xyz999",
+            "118hr",
+            "118hr0",
+            "118hr815xx",
+            "0hr1",
+            "999hr1",
+            "hr815",
+            "118hr99999999999999999999999",
+            "",
+        ];
+        for input in inputs {
+            assert_eq!(Citation::parse(input).is_ok(), validate(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_to_api_url_for() {
+        let citation = Citation::parse("118hr815").unwrap();
+        let base = "https://api.congress.gov/v3/bill/118/hr/815";
+        let details = [
+            (ApiDetail::Overview, ""),
+            (ApiDetail::Actions, "/actions"),
+            (ApiDetail::Cosponsors, "/cosponsors"),
+            (ApiDetail::Committees, "/committees"),
+            (ApiDetail::Amendments, "/amendments"),
+            (ApiDetail::Text, "/text"),
+        ];
+        for (detail, path) in details {
+            assert_eq!(Ok(format!("{base}{path}")), citation.to_api_url_for(detail));
+        }
+        let report = Citation::parse("118hrpt529").unwrap();
+        assert_eq!(
+            Ok("https://api.congress.gov/v3/committee-report/118/hrpt/529/text".to_string()),
+            report.to_api_url_for(ApiDetail::Text)
+        );
+        assert!(report.to_api_url_for(ApiDetail::Actions).is_err());
+        let amendment = Citation::parse("118samdt1").unwrap();
+        assert!(amendment.to_api_url_for(ApiDetail::Actions).is_ok());
+        assert!(amendment.to_api_url_for(ApiDetail::Committees).is_err());
+        assert!(Citation::parse("118hdoc1")
+            .unwrap()
+            .to_api_url_for(ApiDetail::Overview)
+            .is_err());
+    }
+
+    #[test]
+    fn test_citation_list_queries() {
+        let list: CitationList = ["118hr815", "118s5", "117hr1", "118hres7", "118hprivb2"]
+            .iter()
+            .map(|s| s.parse::<Citation>().unwrap())
+            .collect();
+        assert_eq!(5, list.len());
+        let strings =
+            |list: &CitationList| list.iter().map(Citation::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            vec!["118hr815", "117hr1", "118hres7", "118hprivb2"],
+            strings(&list.filter_by_chamber(Chamber::House))
+        );
+        assert_eq!(vec!["117hr1"], strings(&list.filter_by_congress(117)));
+        assert_eq!(
+            vec!["118hr815", "118s5", "118hprivb2"],
+            strings(&list.filter_by_congress(118).bills_only())
+        );
+        assert_eq!(
+            vec!["https://www.congress.gov/bill/117th-congress/house-bill/1"],
+            list.filter_by_congress(117).to_urls()
+        );
+        assert_eq!(5, (&list).into_iter().count());
+        assert_eq!(5, list.into_iter().count());
+    }
+
+    #[test]
+    fn test_to_govtrack_url() {
+        let url = |input| Citation::parse(input).unwrap().to_govtrack_url();
+        assert_eq!(
+            Ok("https://www.govtrack.us/congress/bills/118/s5".to_string()),
+            url("118s5ih")
+        );
+        assert_eq!(
+            Ok("https://www.govtrack.us/congress/bills/117/hjres7".to_string()),
+            url("117hjres7")
+        );
+        assert_eq!(Err(Error::UnknownCongObjectType), url("118hrpt529"));
+        assert_eq!(Err(Error::UnknownCongObjectType), url("118sdoc1"));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parse_many_parallel() {
+        let inputs: Vec<String> = (1..=1000).map(|number| format!("118hr{number}")).collect();
+        let mut inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        inputs.push("118xx1");
+        let inputs = inputs.as_slice();
+        assert_eq!(parse_many(inputs), parse_many_parallel(inputs));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_from_hash_map() {
+        use std::collections::HashMap;
+
+        let parts = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
         };
-        let result = Citation::tokenize(&mut input);
-        assert_eq!(expected, result);
+        let all = [
+            ("congress", "118"),
+            ("chamber", "senate"),
+            ("type", "sjres"),
+            ("number", "7"),
+            ("version", "IS"),
+        ];
+        assert_eq!(
+            Ok(Citation::parse("118sjres7is").unwrap()),
+            Citation::try_from(parts(&all))
+        );
+        assert_eq!(
+            Ok(Citation::parse("118sjres7").unwrap()),
+            Citation::try_from(parts(&all[..4]))
+        );
+        assert_eq!(
+            Err(Error::MissingField("chamber")),
+            Citation::try_from(parts(&[all[0], all[2], all[3]]))
+        );
+        assert_eq!(
+            Err(Error::MissingField("number")),
+            Citation::try_from(parts(&all[..3]))
+        );
+        let mut chamber = all;
+        chamber[1] = ("chamber", "house");
+        assert_eq!(
+            Err(Error::ChamberMismatch),
+            Citation::try_from(parts(&chamber))
+        );
+        chamber[1] = ("chamber", "senado");
+        assert_eq!(
+            Err(Error::InvalidChamber),
+            Citation::try_from(parts(&chamber))
+        );
     }
 
     #[test]
-    fn test_house_bill_to_url() {
-        let input = "118hr529";
-        let expected = "https://www.congress.gov/bill/118th-congress/house-bill/529";
-        let citation = input.parse::<Citation>().unwrap();
-        let result = citation.to_url();
-        assert_eq!(expected, result);
+    fn test_binary_round_trip() {
+        for input in [
+            "118hr815",
+            "118s5enr",
+            "118hconf5",
+            "118erpt1",
+            "1sdoc1",
+            "118hr815rhuc",
+        ] {
+            let citation = Citation::parse(input).unwrap();
+            let bytes = citation.to_bytes();
+            assert_eq!(12, bytes.len());
+            assert_eq!(
+                Ok(citation),
+                Citation::try_from(bytes.as_slice()),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            Error::InvalidBillNumber,
+            *Citation::parse("118hr99999999999")
+                .unwrap_err()
+                .root_cause()
+        );
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            Citation::from_parts(118, Chamber::House, CongObjectType::HouseBill, 1 << 32)
+        );
     }
 
     #[test]
-    fn test_house_bill_with_ver_to_url() {
-        let input = "118hr529ih";
-        let expected = "https://www.congress.gov/bill/118th-congress/house-bill/529/text/ih";
-        let citation = input.parse::<Citation>().unwrap();
-        let result = citation.to_url();
-        assert_eq!(expected, result);
+    fn test_binary_layout_is_stable() {
+        // encodings written by earlier releases must keep decoding to the same citations
+        let encoded: [(&str, [u8; 12]); 4] = [
+            ("118hr815ih", [0, 118, 0, 1, 0, 0, 3, 47, b'i', b'h', 0, 0]),
+            ("118s5", [0, 118, 1, 0, 0, 0, 0, 5, 0, 0, 0, 0]),
+            ("118hdoc1", [0, 118, 0, 17, 0, 0, 0, 1, 0, 0, 0, 0]),
+            ("118sprt2", [0, 118, 1, 20, 0, 0, 0, 2, 0, 0, 0, 0]),
+        ];
+        for (input, bytes) in encoded {
+            let citation = Citation::parse(input).unwrap();
+            assert_eq!(bytes.to_vec(), citation.to_bytes(), "{input}");
+            assert_eq!(Ok(citation), Citation::try_from(bytes.as_slice()));
+        }
+        let bytes = Citation::parse("118hr815rhuc").unwrap().to_bytes();
+        assert_eq!(b"rhuc", &bytes[8..]);
     }
 
     #[test]
-    fn test_house_report_to_url() {
-        let input = "118hrpt529";
-        let expected =
-            "https://www.congress.gov/congressional-report/118th-congress/house-report/529";
-        let citation = input.parse::<Citation>().unwrap();
-        let result = citation.to_url();
-        assert_eq!(expected, result);
+    fn test_binary_errors() {
+        let bytes = Citation::parse("118hr815ih").unwrap().to_bytes();
+        let decode = |bytes: &[u8]| Citation::try_from(bytes);
+        assert_eq!(Err(Error::InvalidBytes), decode(&bytes[..8]));
+        assert_eq!(
+            Err(Error::InvalidBytes),
+            decode(&[bytes.as_slice(), &[0]].concat())
+        );
+        let corrupt = |index: usize, value: u8| {
+            let mut bytes = bytes.clone();
+            bytes[index] = value;
+            decode(&bytes)
+        };
+        assert_eq!(Err(Error::InvalidBytes), corrupt(2, 2));
+        assert_eq!(Err(Error::InvalidBytes), corrupt(3, 21));
+        assert_eq!(Err(Error::InvalidBytes), corrupt(8, b'x'));
+        assert_eq!(Err(Error::InvalidBytes), corrupt(9, b'H'));
+        assert_eq!(Err(Error::InvalidBytes), corrupt(11, b'h'));
+        assert_eq!(Err(Error::ChamberMismatch), corrupt(2, 1));
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            decode(&[0, 118, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            Err(Error::InvalidCongress),
+            corrupt(1, 0).map_err(|e| e.root_cause().clone())
+        );
     }
 
     #[test]
-    fn test_get_version() {
-        let input = "118hr529ih";
-        let expected = Some("ih");
-        let citation = input.parse::<Citation>().unwrap();
-        let result = citation.version();
-        assert_eq!(expected, result);
+    fn test_version_aliases() {
+        let version = |input| {
+            Citation::parse(input)
+                .unwrap()
+                .version()
+                .map(str::to_string)
+        };
+        assert_eq!(Some("enr".to_string()), version("118hr815enacted"));
+        assert_eq!(Some("enr".to_string()), version("118s5ENROLLED"));
+        assert_eq!(Some("ih".to_string()), version("118hr815introduced"));
+        assert_eq!(Some("is".to_string()), version("118s5introduced"));
+        assert_eq!(Some("es".to_string()), version("118-s-5-engrossed"));
+        assert_eq!(Some("rh".to_string()), version("118hres1reported"));
+        assert_eq!(
+            "118hr815enr",
+            Citation::parse("118hr815enacted").unwrap().to_string()
+        );
+        assert!(validate("118s5introduced"));
+        assert!(!validate("118s5passed"));
+        let citation = Citation::parse("118s5").unwrap();
+        assert_eq!(
+            Some("is"),
+            citation.with_version("introduced").unwrap().version()
+        );
+        assert_eq!(Err(Error::NotCanonical), parse_strict("118hr815enacted"));
+    }
+
+    #[test]
+    fn test_related_versions() {
+        let versions = |input| Citation::parse(input).unwrap().related_versions();
+        let house_bill = versions("118hr815");
+        for ver in ["ih", "eh", "rh", "rhuc", "enr", "pap", "pp", "sc"] {
+            assert!(house_bill.contains(&ver), "{ver}");
+        }
+        for ver in ["is", "es", "rs", "ath"] {
+            assert!(!house_bill.contains(&ver), "{ver}");
+        }
+        let senate_bill = versions("118s5");
+        assert!(senate_bill.contains(&"is"));
+        assert!(!senate_bill.contains(&"ih"));
+        assert!(!senate_bill.contains(&"rhuc"));
+        let resolution = versions("118sres12");
+        assert!(resolution.contains(&"ats"));
+        assert!(!resolution.contains(&"enr"));
+        assert!(versions("118hjres7").contains(&"enr"));
+        for ver in house_bill {
+            assert!(Citation::parse("118hr815")
+                .unwrap()
+                .with_version(ver)
+                .is_ok());
+        }
+        assert!(versions("118samdt1").is_empty());
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let citation = Citation::parse("118hr815ih").unwrap();
+        assert_eq!(citation, "118hr815ih");
+        assert_eq!(citation, "118HR815IH");
+        assert_eq!("118hr815ih", citation);
+        assert_eq!(*"118hr815ih", citation);
+        assert_ne!(citation, "118hr815");
+        assert_ne!(citation, "118-hr-815-ih");
+        let list: Vec<_> = parse_filter(&["118hr815", "118s5"]);
+        assert!(list.iter().any(|citation| *citation == "118s5"));
+    }
+
+    #[test]
+    fn test_text_url_for_version() {
+        let citation = Citation::parse("118s5ih").unwrap();
+        assert_eq!(
+            Ok("https://www.congress.gov/bill/118th-congress/senate-bill/5/text/es".to_string()),
+            citation.text_url_for_version("ES")
+        );
+        assert_eq!(
+            Err(Error::InvalidBillVersion),
+            citation.text_url_for_version("xx")
+        );
+    }
+
+    #[test]
+    fn test_parse_gpo_format() {
+        let parse = |input| parse_gpo_format(input, 118).map(|citation| citation.to_string());
+        assert_eq!(Ok("118hr815".to_string()), parse("H.R.815"));
+        assert_eq!(Ok("118hr815".to_string()), parse("H.R. 815"));
+        assert_eq!(Ok("118s5".to_string()), parse("S.5"));
+        assert_eq!(Ok("118hres7".to_string()), parse("H.Res.7"));
+        assert_eq!(Ok("118sconres10".to_string()), parse("S.Con.Res.10"));
+        assert_eq!(Ok("118hjres1".to_string()), parse("H.J.Res.1"));
+        assert_eq!(Ok("118sjres3".to_string()), parse("S.J.Res. 3"));
+        assert_eq!(Err(Error::UnknownCongObjectType), parse("H. J. Res. 1"));
+        assert_eq!(Err(Error::UnknownCongObjectType), parse("h.r.815"));
+        assert_eq!(Err(Error::UnknownCongObjectType), parse("HR815"));
+        assert_eq!(Err(Error::InvalidBillNumber), parse("H.R."));
+        assert!(parse("H.R.  815").is_err());
+        assert_eq!(Err(Error::InvalidCongress), parse_gpo_format("H.R.1", 0));
+    }
+
+    #[test]
+    fn test_version_new() {
+        for (code, description) in BILL_VERSIONS_DESCRIPTIONS {
+            let version = Version::new(code).unwrap();
+            assert_eq!(*code, version.code());
+            assert_eq!(*description, version.description());
+        }
+        assert_eq!(Ok(Version::new("enr").unwrap()), "ENR".parse::<Version>());
+        assert_eq!(Err(Error::InvalidBillVersion), Version::new("enacted"));
+        assert_eq!(Err(Error::InvalidBillVersion), Version::new(""));
+    }
+
+    #[test]
+    fn test_parse_with_default_congress() {
+        let parse = |input| parse_with_default_congress(input, 118);
+        assert_eq!(Ok(Citation::parse("118hr815").unwrap()), parse("hr815"));
+        assert_eq!(Ok(Citation::parse("118s5is").unwrap()), parse("S-5-IS"));
+        assert_eq!(Ok(Citation::parse("100s5").unwrap()), parse("100th-s-5"));
+        let Err(Error::ParseContext {
+            position, fragment, ..
+        }) = parse("hxyz1")
+        else {
+            panic!("expected a parse context");
+        };
+        assert_eq!((0, "hxyz".to_string()), (position, fragment));
+        assert_eq!(
+            Error::InvalidCongress,
+            *parse_with_default_congress("hr1", 0)
+                .unwrap_err()
+                .root_cause()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_short_citation() {
+        let current = *crate::constants::CURRENT_CONGRESS;
+        let citation = Citation::parse(&format!("{current}hr815ih")).unwrap();
+        assert_eq!("hr815ih", citation.to_short_citation());
+        assert_eq!(
+            Ok(citation.clone()),
+            Citation::from_short_citation(&citation.to_short_citation(), current)
+        );
+        let past = Citation::parse("117sres12").unwrap();
+        assert_eq!("117sres12", past.to_short_citation());
+        assert_eq!(
+            Ok(past.clone()),
+            Citation::from_short_citation(&past.to_short_citation(), current)
+        );
+    }
+
+    #[test]
+    fn test_parse_hyphenated_number() {
+        for input in [
+            "pl118-0", "pl118-", "td118-0", "td118-", "pn118-0", "pn118-",
+        ] {
+            let error = parse_hyphenated(input, &["pl", "td", "pn"]).unwrap_err();
+            assert_eq!(Error::InvalidBillNumber, error, "{input}");
+        }
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            PublicLawCitation::parse("pl118-0")
+        );
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            TreatyCitation::parse("td118-")
+        );
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            NominationCitation::parse("pn118-0")
+        );
+        assert!(matches!(
+            parse_hyphenated("pl118-x", &["pl"]),
+            Err(Error::ParseInt(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_mismatched_ordinal_suffix() {
+        assert_eq!(
+            Ok(Citation::parse("118hr1").unwrap()),
+            Citation::parse("118th-hr-1")
+        );
+        assert_eq!(
+            Ok(Citation::parse("102s5").unwrap()),
+            Citation::parse("102nd-s-5")
+        );
+        for input in ["118sthr1", "118ndhr1", "118st-hr-1", "101ths5"] {
+            assert_eq!(
+                Error::InvalidOrdinal,
+                *Citation::parse(input).unwrap_err().root_cause(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_govinfo_url_ignores_version_outside_bills() {
+        let versioned = |input| Citation {
+            ver: Some(Version("ih".to_string())),
+            ..Citation::parse(input).unwrap()
+        };
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CRPT-118hrpt529/html/CRPT-118hrpt529.htm",
+            versioned("118hrpt529").to_govinfo_url().unwrap()
+        );
+        assert_eq!(
+            "https://www.govinfo.gov/content/pkg/CDOC-118hdoc3/xml/CDOC-118hdoc3.xml",
+            versioned("118hdoc3").to_text_url(TextFormat::Xml).unwrap()
+        );
     }
 }
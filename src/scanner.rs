@@ -0,0 +1,106 @@
+use core::ops::Range;
+
+use crate::Citation;
+
+/// An iterator over the citations embedded in a piece of text, yielding each citation along with
+/// the byte range it occupies.
+///
+/// Candidates are runs of letters and digits, optionally joined by hyphens, that begin with a
+/// digit. Candidates that fail to parse are skipped. A span ends with the citation, so anything
+/// after it in the candidate, as in `118hr815ih-extra`, is left out and scanned again. The text is
+/// scanned lazily, one candidate at a time.
+///
+/// Example
+///
+/// ```rust
+/// use capitol::{Citation, CitationScanner};
+///
+/// let text = "Compare 118hr815 with 118s5.";
+/// let mut scanner = CitationScanner::new(text);
+/// let (span, citation) = scanner.next().unwrap();
+/// assert_eq!("118hr815", &text[span]);
+/// assert_eq!(Citation::parse("118hr815").unwrap(), citation);
+/// assert_eq!(Citation::parse("118s5").unwrap(), scanner.next().unwrap().1);
+/// assert!(scanner.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CitationScanner<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> CitationScanner<'a> {
+    /// Create a scanner over the given text.
+    pub fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn is_part(&self, index: usize) -> bool {
+        let bytes = self.input.as_bytes();
+        bytes[index].is_ascii_alphanumeric()
+            || (bytes[index] == b'-' && bytes.get(index + 1).is_some_and(u8::is_ascii_alphanumeric))
+    }
+}
+
+impl Iterator for CitationScanner<'_> {
+    type Item = (Range<usize>, Citation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_bytes();
+        while self.position < bytes.len() {
+            let start = self.position;
+            if !bytes[start].is_ascii_alphanumeric() {
+                self.position += 1;
+                continue;
+            }
+            let mut end = start + 1;
+            while end < bytes.len() && self.is_part(end) {
+                end += 1;
+            }
+            self.position = end;
+            if bytes[start].is_ascii_digit() {
+                let candidate = &self.input[start..end];
+                if let Ok(citation) = Citation::parse(candidate) {
+                    let end = start + Citation::split(candidate).1.end();
+                    self.position = end;
+                    return Some((start..end, citation));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let text = "118hr1, 118-S-5; xx118s9 and 118hres4-";
+        let spans: Vec<_> = CitationScanner::new(text)
+            .map(|(span, _)| &text[span])
+            .collect();
+        assert_eq!(vec!["118hr1", "118-S-5", "118hres4"], spans);
+    }
+
+    #[test]
+    fn test_scanner_is_lazy() {
+        let text = format!("118hr1 {}", "word ".repeat(1000));
+        let mut scanner = CitationScanner::new(&text);
+        assert_eq!(0..6, scanner.next().unwrap().0);
+        assert_eq!(6, scanner.position);
+    }
+
+    #[test]
+    fn test_scanner_span_excludes_trailing_input() {
+        let text = "see 118hr815ih-foo, 118s5is2 and 118hr1ih-118s9";
+        let found: Vec<_> = CitationScanner::new(text)
+            .map(|(span, citation)| {
+                assert_eq!(Ok(citation), Citation::parse(&text[span.clone()]));
+                &text[span]
+            })
+            .collect();
+        assert_eq!(vec!["118hr815ih", "118s5is", "118hr1ih", "118s9"], found);
+    }
+}
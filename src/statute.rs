@@ -0,0 +1,156 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::constants::GOVINFO_BASE_URL;
+use crate::error::Error;
+use crate::{parse_number, Result};
+
+/// Represents a Statutes at Large citation, e.g. `stat118-5` for 118 Stat. 5.
+///
+/// The Statutes at Large compile enacted laws in the order they were passed. A citation names a
+/// volume and the page on which a law begins, optionally followed by a pinpoint page within it,
+/// as in `stat118-5-1000`. Volumes are numbered independently of Congresses (volume 118 covers
+/// 2004, in the 108th Congress), so the volume is kept as a plain number.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StatuteCitation {
+    volume: usize,
+    page: usize,
+    offset: Option<usize>,
+}
+
+impl StatuteCitation {
+    /// Parse a Statutes at Large citation.
+    ///
+    /// The `stat` prefix is accepted in any case.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::StatuteCitation;
+    ///
+    /// let citation = StatuteCitation::parse("stat118-5-1000").unwrap();
+    /// assert_eq!(118, citation.volume());
+    /// assert_eq!(5, citation.page());
+    /// assert_eq!(Some(1000), citation.offset());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will result in an error if the prefix is not recognized, if the hyphen between the volume
+    /// and the page is missing, or if any of the numbers is zero or can't be parsed.
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.to_ascii_lowercase();
+        let rest = input
+            .strip_prefix("stat")
+            .ok_or(Error::UnknownCongObjectType)?;
+        let (volume, rest) = rest.split_once('-').ok_or(Error::MissingHyphen)?;
+        let volume = parse_number(volume)?;
+        let (page, offset) = match rest.split_once('-') {
+            Some((page, offset)) => (page, Some(parse_number(offset)?)),
+            None => (rest, None),
+        };
+        let page = parse_number(page)?;
+
+        Ok(StatuteCitation {
+            volume,
+            page,
+            offset,
+        })
+    }
+
+    /// Get the volume of the Statutes at Large.
+    pub fn volume(&self) -> usize {
+        self.volume
+    }
+
+    /// Get the page on which the law begins.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Get the pinpoint page within the law, if the citation has one.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Converts a `StatuteCitation` to the URL of its volume on GovInfo.gov.
+    ///
+    /// Congress.gov does not publish the Statutes at Large.
+    ///
+    /// Example
+    ///
+    /// ```rust
+    /// use capitol::StatuteCitation;
+    ///
+    /// let url = "stat118-5".parse::<StatuteCitation>().unwrap().to_govinfo_url();
+    /// assert_eq!("https://www.govinfo.gov/content/pkg/STATUTE-118/", url);
+    /// ```
+    pub fn to_govinfo_url(&self) -> String {
+        format!("{GOVINFO_BASE_URL}/content/pkg/STATUTE-{}/", self.volume)
+    }
+}
+
+impl Display for StatuteCitation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "stat{}-{}", self.volume, self.page)?;
+        if let Some(offset) = self.offset {
+            write!(f, "-{offset}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StatuteCitation {
+    type Err = Error;
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_statute() {
+        let expected = StatuteCitation {
+            volume: 118,
+            page: 5,
+            offset: Some(1000),
+        };
+        for input in ["stat118-5-1000", "STAT118-5-1000", "Stat118-5-1000"] {
+            assert_eq!(expected, input.parse().unwrap());
+        }
+        let citation = StatuteCitation::parse("stat1-23").unwrap();
+        assert_eq!(
+            (1, 23, None),
+            (citation.volume(), citation.page(), citation.offset())
+        );
+    }
+
+    #[test]
+    fn test_parse_statute_errors() {
+        assert_eq!(
+            Err(Error::MissingHyphen),
+            StatuteCitation::parse("stat1185")
+        );
+        assert_eq!(
+            Err(Error::UnknownCongObjectType),
+            StatuteCitation::parse("pl118-5")
+        );
+        assert_eq!(
+            Err(Error::InvalidBillNumber),
+            StatuteCitation::parse("stat118-0")
+        );
+        assert!(StatuteCitation::parse("stat118-5-x").is_err());
+    }
+
+    #[test]
+    fn test_statute_round_trip() {
+        for input in ["stat118-5", "stat118-5-1000"] {
+            assert_eq!(input, StatuteCitation::parse(input).unwrap().to_string());
+        }
+    }
+}